@@ -0,0 +1,59 @@
+#ifndef LINKED_LIST_H
+#define LINKED_LIST_H
+
+/* Generated by cbindgen from ffi/src/lib.rs; do not edit. */
+
+#include <stdbool.h>
+#include <stddef.h>
+
+typedef struct LlIter LlIter;
+
+typedef struct LlList LlList;
+
+struct LlList *ll_new(void);
+
+// # Safety
+//
+// `list` must be null or come from [`ll_new`] and not have been freed.
+void ll_free(struct LlList *list);
+
+// # Safety
+//
+// `list` must be a live list from [`ll_new`].
+size_t ll_len(const struct LlList *list);
+
+// # Safety
+//
+// `list` must be a live list from [`ll_new`] with no live iterators.
+void ll_push_back(struct LlList *list, void *key);
+
+// Stores the front element in `out` and returns `true`, or returns `false`
+// and leaves `out` alone if the list is empty.
+//
+// # Safety
+//
+// `list` must be a live list from [`ll_new`] with no live iterators, and
+// `out` must be valid for a pointer-sized write.
+bool ll_pop_front(struct LlList *list, void **out);
+
+// # Safety
+//
+// `list` must be a live list from [`ll_new`]. It must not be modified or
+// freed until the iterator has been passed to [`ll_iter_free`].
+struct LlIter *ll_iter(const struct LlList *list);
+
+// Stores the next element in `out` and returns `true`, or returns `false`
+// once the iterator is exhausted.
+//
+// # Safety
+//
+// `iter` must be a live iterator from [`ll_iter`], and `out` must be valid
+// for a pointer-sized write.
+bool ll_iter_next(struct LlIter *iter, void **out);
+
+// # Safety
+//
+// `iter` must be null or come from [`ll_iter`] and not have been freed.
+void ll_iter_free(struct LlIter *iter);
+
+#endif  /* LINKED_LIST_H */
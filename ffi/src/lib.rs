@@ -0,0 +1,156 @@
+// C bindings over a list of untyped pointers. The list never dereferences
+// or frees the elements; ownership of whatever they point to stays with the
+// caller.
+
+use std::{ffi::c_void, ptr};
+
+use linked_list::{Iter, LinkedList};
+
+pub struct LlList {
+    list: LinkedList<*mut c_void>,
+}
+
+// The lifetime is erased; the C side is told not to touch the list while an
+// iterator over it is alive.
+pub struct LlIter {
+    iter: Iter<'static, *mut c_void>,
+}
+
+#[no_mangle]
+pub extern "C" fn ll_new() -> *mut LlList {
+    Box::into_raw(Box::new(LlList {
+        list: LinkedList::new(),
+    }))
+}
+
+/// # Safety
+///
+/// `list` must be null or come from [`ll_new`] and not have been freed.
+#[no_mangle]
+pub unsafe extern "C" fn ll_free(list: *mut LlList) {
+    if !list.is_null() {
+        drop(Box::from_raw(list));
+    }
+}
+
+/// # Safety
+///
+/// `list` must be a live list from [`ll_new`].
+#[no_mangle]
+pub unsafe extern "C" fn ll_len(list: *const LlList) -> usize {
+    (*list).list.len()
+}
+
+/// # Safety
+///
+/// `list` must be a live list from [`ll_new`] with no live iterators.
+#[no_mangle]
+pub unsafe extern "C" fn ll_push_back(list: *mut LlList, key: *mut c_void) {
+    (*list).list.push_back(key)
+}
+
+/// Stores the front element in `out` and returns `true`, or returns `false`
+/// and leaves `out` alone if the list is empty.
+///
+/// # Safety
+///
+/// `list` must be a live list from [`ll_new`] with no live iterators, and
+/// `out` must be valid for a pointer-sized write.
+#[no_mangle]
+pub unsafe extern "C" fn ll_pop_front(list: *mut LlList, out: *mut *mut c_void) -> bool {
+    match (*list).list.pop_front() {
+        Some(key) => {
+            ptr::write(out, key);
+            true
+        }
+        None => false,
+    }
+}
+
+/// # Safety
+///
+/// `list` must be a live list from [`ll_new`]. It must not be modified or
+/// freed until the iterator has been passed to [`ll_iter_free`].
+#[no_mangle]
+pub unsafe extern "C" fn ll_iter(list: *const LlList) -> *mut LlIter {
+    Box::into_raw(Box::new(LlIter {
+        iter: (*list).list.iter(),
+    }))
+}
+
+/// Stores the next element in `out` and returns `true`, or returns `false`
+/// once the iterator is exhausted.
+///
+/// # Safety
+///
+/// `iter` must be a live iterator from [`ll_iter`], and `out` must be valid
+/// for a pointer-sized write.
+#[no_mangle]
+pub unsafe extern "C" fn ll_iter_next(iter: *mut LlIter, out: *mut *mut c_void) -> bool {
+    match (*iter).iter.next() {
+        Some(&key) => {
+            ptr::write(out, key);
+            true
+        }
+        None => false,
+    }
+}
+
+/// # Safety
+///
+/// `iter` must be null or come from [`ll_iter`] and not have been freed.
+#[no_mangle]
+pub unsafe extern "C" fn ll_iter_free(iter: *mut LlIter) {
+    if !iter.is_null() {
+        drop(Box::from_raw(iter));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(at: usize) -> *mut c_void {
+        ptr::without_provenance_mut(at * 8)
+    }
+
+    #[test]
+    fn push_iterate_pop_and_free() {
+        unsafe {
+            let list = ll_new();
+            for at in 1..=3 {
+                ll_push_back(list, key(at));
+            }
+            assert_eq!(ll_len(list), 3);
+
+            let iter = ll_iter(list);
+            let mut out = ptr::null_mut();
+            let mut seen = Vec::new();
+            while ll_iter_next(iter, &mut out) {
+                seen.push(out);
+            }
+            assert!(!ll_iter_next(iter, &mut out));
+            ll_iter_free(iter);
+            assert_eq!(seen, [key(1), key(2), key(3)]);
+
+            for at in 1..=3 {
+                assert!(ll_pop_front(list, &mut out));
+                assert_eq!(out, key(at));
+            }
+            out = key(9);
+            assert!(!ll_pop_front(list, &mut out));
+            assert_eq!(out, key(9));
+            assert_eq!(ll_len(list), 0);
+
+            ll_free(list);
+        }
+    }
+
+    #[test]
+    fn freeing_null_is_a_no_op() {
+        unsafe {
+            ll_free(ptr::null_mut());
+            ll_iter_free(ptr::null_mut());
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{Iter, LinkedList};
+
+// Both wrappers push at the back, so iteration and `into_inner` keep
+// insertion order.
+pub struct Stack<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> Stack<T> {
+    pub const fn new() -> Stack<T> {
+        Stack {
+            list: LinkedList::new(),
+        }
+    }
+
+    pub fn into_inner(self) -> LinkedList<T> {
+        self.list
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    pub fn push(&mut self, key: T) {
+        self.list.push_back(key)
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.list.iter().next_back()
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<LinkedList<T>> for Stack<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        Stack { list }
+    }
+}
+
+pub struct Queue<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> Queue<T> {
+    pub const fn new() -> Queue<T> {
+        Queue {
+            list: LinkedList::new(),
+        }
+    }
+
+    pub fn into_inner(self) -> LinkedList<T> {
+        self.list
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    pub fn enqueue(&mut self, key: T) {
+        self.list.push_back(key)
+    }
+
+    pub fn dequeue(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.list.iter().next()
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<LinkedList<T>> for Queue<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        Queue { list }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stack_is_last_in_first_out() {
+        let mut stack = Stack::from(LinkedList::from([1, 2]));
+        stack.push(3);
+        assert_eq!(stack.peek(), Some(&3));
+        assert!(stack.iter().eq(&[1, 2, 3]));
+
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.len(), 1);
+        assert!(stack.into_inner().iter().eq(&[1]));
+
+        let mut empty = Stack::<i32>::new();
+        assert!(empty.is_empty() && empty.peek().is_none() && empty.pop().is_none());
+    }
+
+    #[test]
+    fn queue_is_first_in_first_out() {
+        let mut queue = Queue::from(LinkedList::from([1, 2]));
+        queue.enqueue(3);
+        assert_eq!(queue.front(), Some(&1));
+        assert!(queue.iter().eq(&[1, 2, 3]));
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.len(), 1);
+        assert!(queue.into_inner().iter().eq(&[3]));
+
+        let mut empty = Queue::<i32>::new();
+        assert!(empty.is_empty() && empty.front().is_none() && empty.dequeue().is_none());
+    }
+}
@@ -0,0 +1,233 @@
+use core::{borrow::Borrow, mem, ptr::NonNull};
+
+use crate::{LinkedList, Node};
+
+pub enum Entry<'a, K, V> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+// The first node holding the key, found by `entry`.
+pub struct OccupiedEntry<'a, K, V> {
+    list: &'a mut LinkedList<(K, V)>,
+    node: NonNull<Node<(K, V)>>,
+}
+
+pub struct VacantEntry<'a, K, V> {
+    list: &'a mut LinkedList<(K, V)>,
+    key: K,
+}
+
+// Lookups go front to back, so the first entry for a key shadows later ones.
+unsafe impl<K: Send, V: Send> Send for OccupiedEntry<'_, K, V> {}
+
+unsafe impl<K: Sync, V: Sync> Sync for OccupiedEntry<'_, K, V> {}
+
+impl<K, V> LinkedList<(K, V)> {
+    fn find_node<Q>(&self, key: &Q) -> Option<NonNull<Node<(K, V)>>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let mut node = self.head;
+        while let Some(curr) = node {
+            if unsafe { curr.as_ref() }.key.0.borrow() == key {
+                return Some(curr);
+            }
+            node = unsafe { curr.as_ref() }.next;
+        }
+
+        None
+    }
+
+    pub fn get_by_key<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.iter()
+            .find(|(k, _)| k.borrow() == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn get_mut_by_key<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.iter_mut()
+            .find(|(k, _)| k.borrow() == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn remove_by_key<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let node = self.find_node(key)?;
+        Some(unsafe { self.unlink(node) }.1)
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V>
+    where
+        K: Eq,
+    {
+        match self.find_node(&key) {
+            Some(node) => Entry::Occupied(OccupiedEntry { list: self, node }),
+            None => Entry::Vacant(VacantEntry { list: self, key }),
+        }
+    }
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    pub fn or_insert(self, value: V) -> &'a mut V {
+        self.or_insert_with(|| value)
+    }
+
+    pub fn or_insert_with<F>(self, f: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(f()),
+        }
+    }
+
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    pub fn and_modify<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        &unsafe { self.node.as_ref() }.key.0
+    }
+
+    pub fn get(&self) -> &V {
+        &unsafe { self.node.as_ref() }.key.1
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut unsafe { self.node.as_mut() }.key.1
+    }
+
+    pub fn into_mut(mut self) -> &'a mut V {
+        &mut unsafe { self.node.as_mut() }.key.1
+    }
+
+    // Returns the value it replaces.
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    pub fn remove_entry(self) -> (K, V) {
+        unsafe { self.list.unlink(self.node) }
+    }
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    // Appends the pair, so it comes after every entry already in the list.
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.list.push_back((self.key, value));
+        let mut node = unsafe { self.list.tail.unwrap_unchecked() };
+        &mut unsafe { node.as_mut() }.key.1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs() -> LinkedList<(String, i32)> {
+        [("a", 1), ("b", 2), ("a", 3)]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect()
+    }
+
+    #[test]
+    fn first_entry_shadows_later_ones() {
+        let mut list = pairs();
+        assert_eq!(list.get_by_key("a"), Some(&1));
+        *list.get_mut_by_key("a").unwrap() += 10;
+
+        assert_eq!(list.remove_by_key("a"), Some(11));
+        assert_eq!(list.get_by_key("a"), Some(&3));
+        assert_eq!(list.remove_by_key("a"), Some(3));
+        assert_eq!(list.remove_by_key("a"), None);
+        assert!(list.eq_by([("b", 2)], |(k, v), (k2, v2)| k == k2 && *v == v2));
+        list.debug_validate();
+    }
+
+    #[test]
+    fn entry_counts_and_appends() {
+        let mut list = LinkedList::new();
+        for word in "b a b c a b".split(' ') {
+            list.entry(word)
+                .and_modify(|count| *count += 1)
+                .or_insert(1);
+        }
+        assert!(list.eq_by([("b", 3), ("a", 2), ("c", 1)], |a, b| *a == b));
+
+        *list.entry("z").or_default() += 5;
+        assert_eq!(list.back(), Some(&("z", 5)));
+        list.debug_validate();
+    }
+
+    #[test]
+    fn occupied_and_vacant_entries() {
+        let mut list = pairs();
+        match list.entry("a".to_string()) {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(entry.key(), "a");
+                assert_eq!(entry.insert(7), 1);
+                assert_eq!(*entry.get(), 7);
+                assert_eq!(entry.remove_entry(), ("a".to_string(), 7));
+            }
+            Entry::Vacant(_) => panic!("`a` is in the list"),
+        }
+        match list.entry("q".to_string()) {
+            Entry::Vacant(entry) => assert_eq!(entry.into_key(), "q"),
+            Entry::Occupied(_) => panic!("`q` is not in the list"),
+        }
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.get_by_key("a"), Some(&3));
+        list.debug_validate();
+    }
+}
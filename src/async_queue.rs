@@ -0,0 +1,233 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
+};
+
+use crate::LinkedList;
+
+// Each pending `Pop` has one entry in `waiters`, keyed by an id of its own,
+// and a `push` wakes just the oldest of them.
+struct State<T> {
+    list: LinkedList<T>,
+    waiters: LinkedList<(u64, Waker)>,
+    next_waiter: u64,
+}
+
+pub struct AsyncQueue<T> {
+    state: Mutex<State<T>>,
+}
+
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Pop<'a, T> {
+    queue: &'a AsyncQueue<T>,
+    waiter: Option<u64>,
+}
+
+impl<T> Future for Pop<'_, T> {
+    type Output = T;
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let queue = self.queue;
+        let mut state = queue.lock();
+
+        if let Some(key) = state.list.pop_front() {
+            if let Some(id) = self.waiter.take() {
+                state.waiters.remove_by_key(&id);
+            }
+            return Poll::Ready(key);
+        }
+
+        let id = *self.waiter.get_or_insert_with(|| {
+            state.next_waiter += 1;
+            state.next_waiter
+        });
+        match state.waiters.get_mut_by_key(&id) {
+            Some(waker) => {
+                if !waker.will_wake(cx.waker()) {
+                    waker.clone_from(cx.waker());
+                }
+            }
+            None => state.waiters.push_back((id, cx.waker().clone())),
+        }
+
+        Poll::Pending
+    }
+}
+
+// A `Pop` that was woken but is dropped before taking its element hands the
+// wakeup on, so the element is not left sitting there with everyone asleep.
+impl<T> Drop for Pop<'_, T> {
+    fn drop(&mut self) {
+        let Some(id) = self.waiter else {
+            return;
+        };
+        let Ok(mut state) = self.queue.state.lock() else {
+            return;
+        };
+
+        if state.waiters.remove_by_key(&id).is_some() || state.list.is_empty() {
+            return;
+        }
+        if let Some((_, waker)) = state.waiters.pop_front() {
+            drop(state);
+            waker.wake();
+        }
+    }
+}
+
+impl<T> AsyncQueue<T> {
+    pub const fn new() -> AsyncQueue<T> {
+        AsyncQueue {
+            state: Mutex::new(State {
+                list: LinkedList::new(),
+                waiters: LinkedList::new(),
+                next_waiter: 0,
+            }),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().list.is_empty()
+    }
+
+    pub fn push(&self, key: T) {
+        let waiter = {
+            let mut state = self.lock();
+            state.list.push_back(key);
+            state.waiters.pop_front()
+        };
+
+        if let Some((_, waker)) = waiter {
+            waker.wake();
+        }
+    }
+
+    pub fn try_pop(&self) -> Option<T> {
+        self.lock().list.pop_front()
+    }
+
+    pub fn pop(&self) -> Pop<'_, T> {
+        Pop {
+            queue: self,
+            waiter: None,
+        }
+    }
+}
+
+impl<T> Default for AsyncQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        task::Wake,
+    };
+
+    use super::*;
+
+    struct Counter(AtomicUsize);
+
+    impl Wake for Counter {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn counter() -> (Arc<Counter>, Waker) {
+        let counter = Arc::new(Counter(AtomicUsize::new(0)));
+        (counter.clone(), Waker::from(counter))
+    }
+
+    fn poll<T>(pop: &mut Pop<'_, T>, waker: &Waker) -> Poll<T> {
+        Pin::new(pop).poll(&mut Context::from_waker(waker))
+    }
+
+    #[test]
+    fn repolling_keeps_one_waiter() {
+        let queue = AsyncQueue::new();
+        let (count, waker) = counter();
+        let mut pop = queue.pop();
+
+        for _ in 0..10 {
+            assert!(poll(&mut pop, &waker).is_pending());
+        }
+        assert_eq!(queue.lock().waiters.len(), 1);
+
+        queue.push(1);
+        assert_eq!(count.0.load(Ordering::Relaxed), 1);
+        assert_eq!(poll(&mut pop, &waker), Poll::Ready(1));
+        assert!(queue.lock().waiters.is_empty());
+    }
+
+    #[test]
+    fn push_wakes_one_waiter() {
+        let queue = AsyncQueue::new();
+        let (first_count, first_waker) = counter();
+        let (second_count, second_waker) = counter();
+        let mut first = queue.pop();
+        let mut second = queue.pop();
+        assert!(poll(&mut first, &first_waker).is_pending());
+        assert!(poll(&mut second, &second_waker).is_pending());
+
+        queue.push(1);
+        assert_eq!(first_count.0.load(Ordering::Relaxed), 1);
+        assert_eq!(second_count.0.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn dropped_pop_leaves_no_waiter() {
+        let queue = AsyncQueue::<i32>::new();
+        let (_, waker) = counter();
+        let mut pop = queue.pop();
+        assert!(poll(&mut pop, &waker).is_pending());
+        drop(pop);
+
+        assert!(queue.lock().waiters.is_empty());
+    }
+
+    #[test]
+    fn dropped_woken_pop_passes_the_wakeup_on() {
+        let queue = AsyncQueue::new();
+        let (_, first_waker) = counter();
+        let (second_count, second_waker) = counter();
+        let mut first = queue.pop();
+        let mut second = queue.pop();
+        assert!(poll(&mut first, &first_waker).is_pending());
+        assert!(poll(&mut second, &second_waker).is_pending());
+
+        queue.push(1);
+        drop(first);
+        assert_eq!(second_count.0.load(Ordering::Relaxed), 1);
+        assert_eq!(poll(&mut second, &second_waker), Poll::Ready(1));
+    }
+
+    #[test]
+    fn changed_waker_replaces_the_old_one() {
+        let queue = AsyncQueue::new();
+        let (old_count, old_waker) = counter();
+        let (new_count, new_waker) = counter();
+        let mut pop = queue.pop();
+        assert!(poll(&mut pop, &old_waker).is_pending());
+        assert!(poll(&mut pop, &new_waker).is_pending());
+
+        queue.push(1);
+        assert_eq!(old_count.0.load(Ordering::Relaxed), 0);
+        assert_eq!(new_count.0.load(Ordering::Relaxed), 1);
+    }
+}
@@ -0,0 +1,28 @@
+// The atomics everything outside the loom shim uses: core's, or with the
+// `portable-atomic` feature that crate's, which also fills in the `Arc` that
+// `alloc` leaves out where there is no native compare-and-swap. Built
+// without `std`, that lets such targets use the SPSC and MPSC queues.
+
+#[cfg(feature = "portable-atomic")]
+pub(crate) use portable_atomic::{AtomicPtr, AtomicUsize, Ordering};
+#[cfg(feature = "portable-atomic")]
+pub(crate) use portable_atomic_util::Arc;
+
+#[cfg(not(feature = "portable-atomic"))]
+pub(crate) use alloc::sync::Arc;
+#[cfg(not(feature = "portable-atomic"))]
+pub(crate) use core::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+// Only the structures that need `std` for their reclamation use these.
+#[cfg(all(feature = "portable-atomic", feature = "std"))]
+pub(crate) use portable_atomic::{fence, AtomicBool};
+
+#[cfg(all(not(feature = "portable-atomic"), feature = "std"))]
+pub(crate) use core::sync::atomic::{fence, AtomicBool};
+
+// Only the work-stealing deque wants it, and under loom that takes loom's.
+#[cfg(all(feature = "portable-atomic", feature = "std", not(loom)))]
+pub(crate) use portable_atomic::AtomicIsize;
+
+#[cfg(all(not(feature = "portable-atomic"), feature = "std", not(loom)))]
+pub(crate) use core::sync::atomic::AtomicIsize;
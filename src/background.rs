@@ -0,0 +1,79 @@
+use std::{
+    sync::{
+        mpsc::{self, Sender},
+        OnceLock,
+    },
+    thread,
+};
+
+use crate::LinkedList;
+
+type Garbage = Box<dyn Send>;
+
+// One thread, started on first use, drops whatever it is sent. Handing it a
+// list moves just the list header, so the caller pays the same small cost
+// whatever the length.
+fn reclaimer() -> Option<&'static Sender<Garbage>> {
+    static RECLAIMER: OnceLock<Option<Sender<Garbage>>> = OnceLock::new();
+
+    RECLAIMER
+        .get_or_init(|| {
+            let (sender, receiver) = mpsc::channel::<Garbage>();
+            thread::Builder::new()
+                .name("linked_list-drop".into())
+                .spawn(move || receiver.into_iter().for_each(drop))
+                .ok()?;
+            Some(sender)
+        })
+        .as_ref()
+}
+
+impl<T: Send + 'static> LinkedList<T> {
+    // Falls back to dropping on the calling thread if the reclaimer thread
+    // could not be started.
+    pub fn drop_in_background(self) {
+        if self.is_empty() {
+            return;
+        }
+
+        let Some(reclaimer) = reclaimer() else {
+            return drop(self);
+        };
+
+        if let Err(mpsc::SendError(garbage)) = reclaimer.send(Box::new(self)) {
+            drop(garbage);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    // Reports the name of the thread it is dropped on.
+    struct Reporter(Sender<Option<String>>);
+
+    impl Drop for Reporter {
+        fn drop(&mut self) {
+            let _ = self.0.send(thread::current().name().map(String::from));
+        }
+    }
+
+    #[test]
+    fn elements_are_dropped_on_the_reclaimer_thread() {
+        let (sender, receiver) = mpsc::channel();
+        let list: LinkedList<_> = (0..100).map(|_| Reporter(sender.clone())).collect();
+        drop(sender);
+
+        list.drop_in_background();
+        for _ in 0..100 {
+            let name = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+            assert_eq!(name.as_deref(), Some("linked_list-drop"));
+        }
+        // No report is left over once every sender is gone, so each element
+        // was dropped exactly once.
+        assert!(receiver.recv().is_err());
+    }
+}
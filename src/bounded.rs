@@ -0,0 +1,151 @@
+use core::ops::Deref;
+
+use crate::LinkedList;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    Reject,
+    EvictFront,
+    EvictBack,
+}
+
+pub struct BoundedList<T> {
+    list: LinkedList<T>,
+    capacity: usize,
+    policy: EvictionPolicy,
+}
+
+impl<T> BoundedList<T> {
+    pub const fn new(capacity: usize, policy: EvictionPolicy) -> BoundedList<T> {
+        BoundedList {
+            list: LinkedList::new(),
+            capacity,
+            policy,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn policy(&self) -> EvictionPolicy {
+        self.policy
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.list.len() >= self.capacity
+    }
+
+    pub fn into_inner(self) -> LinkedList<T> {
+        self.list
+    }
+
+    // Makes room for one more element according to the policy. Returns the
+    // evicted element, or `Err(key)` if `key` has to be rejected instead.
+    fn make_room(&mut self, key: T) -> Result<(T, Option<T>), T> {
+        if !self.is_full() {
+            return Ok((key, None));
+        }
+
+        if self.capacity == 0 {
+            return Err(key);
+        }
+
+        match self.policy {
+            EvictionPolicy::Reject => Err(key),
+            EvictionPolicy::EvictFront => Ok((key, self.list.pop_front())),
+            EvictionPolicy::EvictBack => Ok((key, self.list.pop_back())),
+        }
+    }
+
+    pub fn push_front(&mut self, key: T) -> Option<T> {
+        match self.make_room(key) {
+            Ok((key, evicted)) => {
+                self.list.push_front(key);
+                evicted
+            }
+            Err(key) => Some(key),
+        }
+    }
+
+    pub fn push_back(&mut self, key: T) -> Option<T> {
+        match self.make_room(key) {
+            Ok((key, evicted)) => {
+                self.list.push_back(key);
+                evicted
+            }
+            Err(key) => Some(key),
+        }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> Deref for BoundedList<T> {
+    type Target = LinkedList<T>;
+    fn deref(&self) -> &LinkedList<T> {
+        &self.list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled(policy: EvictionPolicy) -> BoundedList<i32> {
+        let mut list = BoundedList::new(3, policy);
+        for key in 0..3 {
+            assert_eq!(list.push_back(key), None);
+        }
+        assert!(list.is_full());
+        list
+    }
+
+    #[test]
+    fn each_policy_hands_back_the_right_element() {
+        let mut list = filled(EvictionPolicy::Reject);
+        assert_eq!(list.push_back(3), Some(3));
+        assert_eq!(list.push_front(-1), Some(-1));
+        assert!(list.iter().eq(&[0, 1, 2]));
+
+        let mut list = filled(EvictionPolicy::EvictFront);
+        assert_eq!(list.push_back(3), Some(0));
+        assert_eq!(list.push_front(-1), Some(1));
+        assert!(list.iter().eq(&[-1, 2, 3]));
+
+        let mut list = filled(EvictionPolicy::EvictBack);
+        assert_eq!(list.push_front(-1), Some(2));
+        assert_eq!(list.push_back(3), Some(1));
+        assert!(list.iter().eq(&[-1, 0, 3]));
+        assert_eq!(list.len(), list.capacity());
+    }
+
+    #[test]
+    fn popping_makes_room_again() {
+        let mut list = filled(EvictionPolicy::Reject);
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(list.push_back(3), None);
+        assert_eq!(list.pop_back(), Some(3));
+        assert!(list.into_inner().iter().eq(&[1, 2]));
+    }
+
+    #[test]
+    fn zero_capacity_rejects_under_every_policy() {
+        for policy in [
+            EvictionPolicy::Reject,
+            EvictionPolicy::EvictFront,
+            EvictionPolicy::EvictBack,
+        ] {
+            let mut list = BoundedList::new(0, policy);
+            assert_eq!(list.push_back(1), Some(1));
+            assert_eq!(list.push_front(2), Some(2));
+            assert!(list.is_empty() && list.policy() == policy);
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use core::{alloc::Layout, ptr::NonNull};
+
+// With `node-cache`, freed node memory is kept in a per-thread cache keyed by
+// layout and handed back to the next allocation of the same layout on that
+// thread, whichever list it is for. Each layout keeps at most `CAPACITY`
+// blocks; the rest go back to the allocator, as does everything once the
+// thread exits.
+#[cfg(feature = "node-cache")]
+mod imp {
+    use std::{
+        alloc::{self, Layout},
+        cell::RefCell,
+        ptr::NonNull,
+    };
+
+    const CAPACITY: usize = 1024;
+
+    struct Cache {
+        bins: RefCell<Vec<(Layout, Vec<NonNull<u8>>)>>,
+    }
+
+    impl Drop for Cache {
+        fn drop(&mut self) {
+            for (layout, blocks) in self.bins.get_mut().drain(..) {
+                for block in blocks {
+                    unsafe { alloc::dealloc(block.as_ptr(), layout) };
+                }
+            }
+        }
+    }
+
+    thread_local! {
+        static CACHE: Cache = const {
+            Cache {
+                bins: RefCell::new(Vec::new()),
+            }
+        };
+    }
+
+    pub(super) fn take(layout: Layout) -> Option<NonNull<u8>> {
+        CACHE
+            .try_with(|cache| {
+                let mut bins = cache.bins.borrow_mut();
+                let (_, blocks) = bins.iter_mut().find(|(other, _)| *other == layout)?;
+                blocks.pop()
+            })
+            .ok()
+            .flatten()
+    }
+
+    // Returns `ptr` back if the cache has no room for it.
+    pub(super) fn give(ptr: NonNull<u8>, layout: Layout) -> Option<NonNull<u8>> {
+        CACHE
+            .try_with(|cache| {
+                let mut bins = cache.bins.borrow_mut();
+                let at = match bins.iter().position(|(other, _)| *other == layout) {
+                    Some(at) => at,
+                    None => {
+                        bins.push((layout, Vec::new()));
+                        bins.len() - 1
+                    }
+                };
+
+                let blocks = &mut bins[at].1;
+                if blocks.len() == CAPACITY {
+                    return Some(ptr);
+                }
+                blocks.push(ptr);
+                None
+            })
+            .unwrap_or(Some(ptr))
+    }
+}
+
+// Also says whether the block came out of the cache.
+pub(crate) fn alloc(layout: Layout) -> (NonNull<u8>, bool) {
+    #[cfg(feature = "node-cache")]
+    if let Some(ptr) = imp::take(layout) {
+        return (ptr, true);
+    }
+
+    match NonNull::new(unsafe { alloc::alloc::alloc(layout) }) {
+        Some(ptr) => (ptr, false),
+        None => alloc::alloc::handle_alloc_error(layout),
+    }
+}
+
+// `ptr` must come from `alloc` or the global allocator with this `layout`.
+pub(crate) unsafe fn dealloc(ptr: NonNull<u8>, layout: Layout) {
+    #[cfg(feature = "node-cache")]
+    let Some(ptr) = imp::give(ptr, layout) else {
+        return;
+    };
+
+    alloc::alloc::dealloc(ptr.as_ptr(), layout)
+}
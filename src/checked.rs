@@ -0,0 +1,154 @@
+use alloc::vec::Vec;
+use core::ops::RangeBounds;
+
+use crate::{Chunks, LinkedList, SubList, SubListMut};
+
+// Counterparts of the methods that assert on their arguments, reporting the
+// bad argument through the return value instead of panicking.
+impl<T> LinkedList<T> {
+    pub fn try_push_at(&mut self, at: usize, key: T) -> Result<(), T> {
+        if at > self.len() {
+            return Err(key);
+        }
+
+        self.push_at(at, key);
+        Ok(())
+    }
+
+    pub fn try_pop_at(&mut self, at: usize) -> Option<T> {
+        if at >= self.len() {
+            return None;
+        }
+
+        self.pop_at(at)
+    }
+
+    pub fn try_chunks(&self, size: usize) -> Option<Chunks<'_, T>> {
+        if size == 0 {
+            return None;
+        }
+
+        Some(self.chunks(size))
+    }
+
+    pub fn try_split_off(&mut self, at: usize) -> Option<LinkedList<T>> {
+        if at > self.len() {
+            return None;
+        }
+
+        Some(self.split_off(at))
+    }
+
+    // Hands the list back if `n` is zero.
+    pub fn try_split_into(self, n: usize) -> Result<Vec<LinkedList<T>>, LinkedList<T>> {
+        if n == 0 {
+            return Err(self);
+        }
+
+        Ok(self.split_into(n))
+    }
+
+    // Leaves the list as it was if any index is out of bounds.
+    pub fn try_remove_indices<I>(&mut self, indices: I) -> Option<Vec<T>>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let indices: Vec<usize> = indices.into_iter().collect();
+        if indices.iter().any(|&at| at >= self.len()) {
+            return None;
+        }
+
+        Some(self.remove_indices(indices))
+    }
+
+    fn is_valid_range<R>(&self, range: &R) -> bool
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = self.range_ends(range);
+        start <= end && end <= self.len()
+    }
+
+    pub fn try_range<R>(&self, range: R) -> Option<SubList<'_, T>>
+    where
+        R: RangeBounds<usize>,
+    {
+        if !self.is_valid_range(&range) {
+            return None;
+        }
+
+        Some(self.range(range))
+    }
+
+    pub fn try_range_mut<R>(&mut self, range: R) -> Option<SubListMut<'_, T>>
+    where
+        R: RangeBounds<usize>,
+    {
+        if !self.is_valid_range(&range) {
+            return None;
+        }
+
+        Some(self.range_mut(range))
+    }
+
+    pub fn try_split_at_mut(
+        &mut self,
+        at: usize,
+    ) -> Option<(SubListMut<'_, T>, SubListMut<'_, T>)> {
+        if at > self.len() {
+            return None;
+        }
+
+        Some(self.split_at_mut(at))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LinkedList;
+
+    #[test]
+    fn out_of_bounds_arguments_are_refused() {
+        let mut l = LinkedList::from([0, 1, 2, 3]);
+        assert!(l.try_push_at(5, 9).is_err());
+        assert!(l.try_pop_at(4).is_none());
+        assert!(l.try_chunks(0).is_none());
+        assert!(l.try_split_off(5).is_none());
+        assert!(l.try_remove_indices([0, 4]).is_none());
+        let (start, end) = (3, 2);
+        assert!(l.try_range(start..end).is_none());
+        assert!(l.try_range(..=4).is_none());
+        assert!(l.try_range(..=usize::MAX).is_none());
+        assert!(l.try_range_mut(2..5).is_none());
+        assert!(l.try_split_at_mut(5).is_none());
+        assert!(l.eq_by(0..4, |a, b| *a == b));
+
+        let l = l.try_split_into(0).unwrap_err();
+        assert_eq!(l.len(), 4);
+    }
+
+    #[test]
+    fn in_bounds_arguments_match_the_asserting_forms() {
+        let mut l = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        assert!(l.try_range(1..=2).unwrap().iter().eq(&[1, 2]));
+        assert!(l.try_range(6..).unwrap().is_empty());
+        l.try_range_mut(..2)
+            .unwrap()
+            .iter_mut()
+            .for_each(|key| *key += 10);
+
+        let (front, back) = l.try_split_at_mut(6).unwrap();
+        assert_eq!((front.len(), back.len()), (6, 0));
+
+        assert_eq!(l.try_remove_indices([5, 0, 5]), Some(vec![10, 5]));
+        let back = l.try_split_off(2).unwrap();
+        assert!(l.eq_by([11, 2], |a, b| *a == b));
+        assert!(back.eq_by([3, 4], |a, b| *a == b));
+
+        let parts = back.try_split_into(3).unwrap();
+        assert_eq!(
+            parts.iter().map(LinkedList::len).collect::<Vec<_>>(),
+            [1, 1, 0]
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use std::io::{self, Read, Write};
+
+use crate::LinkedList;
+
+// Snapshots are a little-endian `u64` element count followed by the
+// elements in order, each written by the caller's encoder.
+impl<T> LinkedList<T> {
+    pub fn write_to<W, F>(&self, mut writer: W, mut encode: F) -> io::Result<()>
+    where
+        W: Write,
+        F: FnMut(&T, &mut W) -> io::Result<()>,
+    {
+        writer.write_all(&(self.len as u64).to_le_bytes())?;
+        for key in self.iter() {
+            encode(key, &mut writer)?;
+        }
+
+        writer.flush()
+    }
+
+    pub fn read_from<R, F>(mut reader: R, mut decode: F) -> io::Result<LinkedList<T>>
+    where
+        R: Read,
+        F: FnMut(&mut R) -> io::Result<T>,
+    {
+        let mut len = [0; 8];
+        reader.read_exact(&mut len)?;
+
+        let mut list = LinkedList::new();
+        for _ in 0..u64::from_le_bytes(len) {
+            list.push_back(decode(&mut reader)?);
+        }
+
+        Ok(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(key: &u32, writer: &mut &mut Vec<u8>) -> io::Result<()> {
+        writer.write_all(&key.to_le_bytes())
+    }
+
+    fn decode(reader: &mut &[u8]) -> io::Result<u32> {
+        let mut bytes = [0; 4];
+        reader.read_exact(&mut bytes)?;
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    #[test]
+    fn snapshots_round_trip() {
+        for len in [0, 1, 7] {
+            let list: LinkedList<u32> = (0..len).map(|key| key * 0x0101).collect();
+
+            let mut bytes = Vec::new();
+            list.write_to(&mut bytes, encode).unwrap();
+            assert_eq!(bytes.len(), 8 + 4 * len as usize);
+            assert_eq!(bytes[..8], (len as u64).to_le_bytes());
+
+            let read = LinkedList::read_from(&bytes[..], decode).unwrap();
+            assert_eq!(read, list);
+        }
+    }
+
+    #[test]
+    fn reading_a_truncated_snapshot_fails() {
+        let mut bytes = Vec::new();
+        LinkedList::from([1, 2, 3])
+            .write_to(&mut bytes, encode)
+            .unwrap();
+
+        for end in [0, 5, bytes.len() - 1] {
+            let error = LinkedList::read_from(&bytes[..end], decode).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+        }
+    }
+}
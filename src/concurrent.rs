@@ -0,0 +1,28 @@
+// The reclaimers keep their bookkeeping in std thread-locals and mutexes, so
+// only the SPSC and MPSC queues, which need no reclamation, build without
+// `std`.
+
+#[cfg(feature = "std")]
+mod list;
+mod mpsc;
+#[cfg(feature = "std")]
+mod queue;
+#[cfg(feature = "std")]
+pub mod reclaim;
+mod spsc;
+#[cfg(feature = "std")]
+mod stack;
+pub(crate) mod sync;
+#[cfg(feature = "std")]
+mod work_stealing;
+
+#[cfg(feature = "std")]
+pub use list::ConcurrentList;
+pub use mpsc::{MpscConsumer, MpscNode, MpscProducer, MpscQueue};
+#[cfg(feature = "std")]
+pub use queue::LockFreeQueue;
+pub use spsc::{Consumer, Producer, SpscQueue};
+#[cfg(feature = "std")]
+pub use stack::{LockFreeStack, StackIter};
+#[cfg(feature = "std")]
+pub use work_stealing::{Stealer, WorkStealingDeque};
@@ -0,0 +1,117 @@
+use std::sync::{Mutex, MutexGuard};
+
+use crate::LinkedList;
+
+pub struct ConcurrentList<T> {
+    inner: Mutex<LinkedList<T>>,
+}
+
+impl<T> ConcurrentList<T> {
+    pub const fn new() -> ConcurrentList<T> {
+        ConcurrentList {
+            inner: Mutex::new(LinkedList::new()),
+        }
+    }
+
+    pub fn lock(&self) -> MutexGuard<'_, LinkedList<T>> {
+        self.inner.lock().unwrap()
+    }
+
+    pub fn into_inner(self) -> LinkedList<T> {
+        self.inner.into_inner().unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    pub fn push_front(&self, key: T) {
+        self.lock().push_front(key)
+    }
+
+    pub fn push_back(&self, key: T) {
+        self.lock().push_back(key)
+    }
+
+    pub fn pop_front(&self) -> Option<T> {
+        self.lock().pop_front()
+    }
+
+    pub fn pop_back(&self) -> Option<T> {
+        self.lock().pop_back()
+    }
+
+    pub fn contains(&self, key: &T) -> bool
+    where
+        T: PartialEq<T>,
+    {
+        self.lock().contains(key)
+    }
+}
+
+impl<T> Default for ConcurrentList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<LinkedList<T>> for ConcurrentList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        ConcurrentList {
+            inner: Mutex::new(list),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    // Each thread pushes its keys at both ends and pops some back, so every
+    // operation contends for the lock.
+    #[test]
+    fn contended_pushes_and_pops_all_count() {
+        static LIST: ConcurrentList<usize> = ConcurrentList::new();
+
+        thread::scope(|scope| {
+            for thread in 0..4 {
+                scope.spawn(move || {
+                    for key in 0..1_000 {
+                        let key = thread * 1_000 + key;
+                        match key % 4 {
+                            0 => LIST.push_front(key),
+                            1 => LIST.push_back(key),
+                            2 => {
+                                LIST.push_back(key);
+                                assert!(LIST.pop_back().is_some());
+                            }
+                            _ => {
+                                LIST.push_front(key);
+                                assert!(LIST.pop_front().is_some());
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        let list = LIST.lock();
+        assert_eq!(list.len(), 2_000);
+        list.debug_validate();
+    }
+
+    #[test]
+    fn wraps_and_unwraps_a_list() {
+        let list = ConcurrentList::from(LinkedList::from([1, 2, 3]));
+        assert!(list.contains(&2) && !list.is_empty());
+        assert_eq!(list.pop_front(), Some(1));
+        list.lock().push_back(4);
+        assert!(list.into_inner().iter().eq(&[2, 3, 4]));
+    }
+}
@@ -0,0 +1,251 @@
+use alloc::boxed::Box;
+use core::{
+    cell::{Cell, UnsafeCell},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr,
+};
+
+use super::sync::{Arc, AtomicPtr, Ordering};
+
+struct Link {
+    next: AtomicPtr<Link>,
+}
+
+impl Link {
+    fn new() -> Link {
+        Link {
+            next: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+}
+
+// The link comes first so a `*mut MpscNode<T>` can be used as a `*mut Link`.
+#[repr(C)]
+pub struct MpscNode<T> {
+    link: Link,
+    value: T,
+}
+
+impl<T> MpscNode<T> {
+    pub fn new(value: T) -> Box<MpscNode<T>> {
+        Box::new(MpscNode {
+            link: Link::new(),
+            value,
+        })
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for MpscNode<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for MpscNode<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+// Vyukov's intrusive queue: producers swap themselves in as `head` and then
+// link the previous head to them; the consumer follows `next` from `tail`,
+// re-inserting the stub whenever it would otherwise hand out the last node.
+pub struct MpscQueue<T> {
+    head: AtomicPtr<Link>,
+    tail: UnsafeCell<*mut Link>,
+    stub: *mut Link,
+    marker: PhantomData<Box<MpscNode<T>>>,
+}
+
+unsafe impl<T: Send> Send for MpscQueue<T> {}
+
+unsafe impl<T: Send> Sync for MpscQueue<T> {}
+
+#[derive(Clone)]
+pub struct MpscProducer<T> {
+    queue: Arc<MpscQueue<T>>,
+}
+
+// There is only ever one, and it cannot be shared, so `pop` never runs on
+// two threads at once.
+pub struct MpscConsumer<T> {
+    queue: Arc<MpscQueue<T>>,
+    marker: PhantomData<Cell<()>>,
+}
+
+impl<T> MpscQueue<T> {
+    pub fn new() -> MpscQueue<T> {
+        let stub = Box::into_raw(Box::new(Link::new()));
+
+        MpscQueue {
+            head: AtomicPtr::new(stub),
+            tail: UnsafeCell::new(stub),
+            stub,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn push(&self, node: Box<MpscNode<T>>) {
+        self.push_link(Box::into_raw(node) as *mut Link);
+    }
+
+    pub fn split(self) -> (MpscProducer<T>, MpscConsumer<T>) {
+        let queue = Arc::new(self);
+
+        let producer = MpscProducer {
+            queue: queue.clone(),
+        };
+        let consumer = MpscConsumer {
+            queue,
+            marker: PhantomData,
+        };
+
+        (producer, consumer)
+    }
+
+    fn push_link(&self, link: *mut Link) {
+        unsafe { (*link).next.store(ptr::null_mut(), Ordering::Relaxed) };
+        let prev = self.head.swap(link, Ordering::AcqRel);
+        unsafe { (*prev).next.store(link, Ordering::Release) };
+    }
+
+    // At most one thread may call this at a time.
+    unsafe fn pop(&self) -> Option<Box<MpscNode<T>>> {
+        let tail = &mut *self.tail.get();
+
+        let mut next = (**tail).next.load(Ordering::Acquire);
+
+        if *tail == self.stub {
+            if next.is_null() {
+                return None;
+            }
+            *tail = next;
+            next = (*next).next.load(Ordering::Acquire);
+        }
+
+        if !next.is_null() {
+            let node = *tail;
+            *tail = next;
+            return Some(Box::from_raw(node as *mut MpscNode<T>));
+        }
+
+        if *tail != self.head.load(Ordering::Acquire) {
+            return None;
+        }
+
+        self.push_link(self.stub);
+
+        next = (**tail).next.load(Ordering::Acquire);
+        if !next.is_null() {
+            let node = *tail;
+            *tail = next;
+            return Some(Box::from_raw(node as *mut MpscNode<T>));
+        }
+
+        None
+    }
+}
+
+impl<T> MpscProducer<T> {
+    pub fn push(&self, node: Box<MpscNode<T>>) {
+        self.queue.push(node);
+    }
+}
+
+impl<T> MpscConsumer<T> {
+    /// Returns `None` both when the queue is empty and when a producer has
+    /// swapped in a node but not linked it yet; retry later in that case.
+    pub fn pop(&mut self) -> Option<Box<MpscNode<T>>> {
+        unsafe { self.queue.pop() }
+    }
+}
+
+impl<T> Default for MpscQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for MpscQueue<T> {
+    fn drop(&mut self) {
+        while unsafe { self.pop() }.is_some() {}
+
+        let _ = unsafe { Box::from_raw(self.stub) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::atomic::AtomicUsize, thread};
+
+    use super::*;
+
+    #[test]
+    fn each_producer_is_received_in_order() {
+        const PRODUCERS: usize = 4;
+        const PER_PRODUCER: usize = 10_000;
+
+        let (producer, mut consumer) = MpscQueue::new().split();
+        let handles: Vec<_> = (0..PRODUCERS)
+            .map(|id| {
+                let producer = producer.clone();
+                thread::spawn(move || {
+                    for seq in 0..PER_PRODUCER {
+                        producer.push(MpscNode::new((id, seq)));
+                    }
+                })
+            })
+            .collect();
+        drop(producer);
+
+        let mut next = [0; PRODUCERS];
+        let mut received = 0;
+        while received < PRODUCERS * PER_PRODUCER {
+            let Some(node) = consumer.pop() else {
+                thread::yield_now();
+                continue;
+            };
+
+            let (id, seq) = node.into_inner();
+            assert_eq!(seq, next[id], "producer {} out of order", id);
+            next[id] += 1;
+            received += 1;
+        }
+
+        handles
+            .into_iter()
+            .for_each(|handle| handle.join().unwrap());
+        assert!(consumer.pop().is_none());
+    }
+
+    #[test]
+    fn dropping_the_queue_frees_what_is_left() {
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        struct Counted;
+
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let queue = MpscQueue::new();
+        for _ in 0..5 {
+            queue.push(MpscNode::new(Counted));
+        }
+        let (producer, mut consumer) = queue.split();
+        drop(consumer.pop());
+        assert_eq!(DROPS.load(Ordering::Relaxed), 1);
+
+        drop(producer);
+        drop(consumer);
+        assert_eq!(DROPS.load(Ordering::Relaxed), 5);
+    }
+}
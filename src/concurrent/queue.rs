@@ -0,0 +1,209 @@
+use core::{mem::MaybeUninit, ptr};
+
+use super::{
+    reclaim::{Epoch, Guard, Reclaim},
+    sync::{AtomicPtr, Ordering},
+};
+
+struct Node<T> {
+    key: MaybeUninit<T>,
+    next: AtomicPtr<Node<T>>,
+}
+
+impl<T> Node<T> {
+    fn new(key: MaybeUninit<T>) -> *mut Node<T> {
+        Box::into_raw(Box::new(Node {
+            key,
+            next: AtomicPtr::new(ptr::null_mut()),
+        }))
+    }
+}
+
+// Michael–Scott queue: `head` always points at a dummy node whose successor
+// holds the front element, and `tail` lags the last node by at most one link.
+pub struct LockFreeQueue<T, R = Epoch> {
+    head: AtomicPtr<Node<T>>,
+    tail: AtomicPtr<Node<T>>,
+    reclaimer: R,
+}
+
+unsafe impl<T: Send, R: Send> Send for LockFreeQueue<T, R> {}
+
+unsafe impl<T: Send, R: Sync> Sync for LockFreeQueue<T, R> {}
+
+impl<T> LockFreeQueue<T> {
+    pub fn new() -> LockFreeQueue<T> {
+        LockFreeQueue::with_reclaimer(Epoch)
+    }
+}
+
+impl<T, R: Reclaim> LockFreeQueue<T, R> {
+    pub fn with_reclaimer(reclaimer: R) -> LockFreeQueue<T, R> {
+        let dummy = Node::new(MaybeUninit::uninit());
+
+        LockFreeQueue {
+            head: AtomicPtr::new(dummy),
+            tail: AtomicPtr::new(dummy),
+            reclaimer,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        let guard = self.reclaimer.pin();
+
+        let head = guard.protect(0, &self.head);
+        unsafe { (*head).next.load(Ordering::Acquire) }.is_null()
+    }
+
+    pub fn push(&self, key: T) {
+        let node = Node::new(MaybeUninit::new(key));
+        let guard = self.reclaimer.pin();
+
+        loop {
+            let tail = guard.protect(0, &self.tail);
+            let next = unsafe { (*tail).next.load(Ordering::Acquire) };
+
+            if tail != self.tail.load(Ordering::Acquire) {
+                continue;
+            }
+
+            if next.is_null() {
+                let linked = unsafe { &(*tail).next }.compare_exchange(
+                    ptr::null_mut(),
+                    node,
+                    Ordering::Release,
+                    Ordering::Relaxed,
+                );
+                if linked.is_ok() {
+                    let _ = self.tail.compare_exchange(
+                        tail,
+                        node,
+                        Ordering::Release,
+                        Ordering::Relaxed,
+                    );
+                    return;
+                }
+            } else {
+                let _ =
+                    self.tail
+                        .compare_exchange(tail, next, Ordering::Release, Ordering::Relaxed);
+            }
+        }
+    }
+
+    pub fn pop(&self) -> Option<T> {
+        let guard = self.reclaimer.pin();
+
+        loop {
+            let head = guard.protect(0, &self.head);
+            let tail = self.tail.load(Ordering::Acquire);
+            let next = guard.protect(1, unsafe { &(*head).next });
+
+            if head != self.head.load(Ordering::Acquire) {
+                continue;
+            }
+
+            if next.is_null() {
+                return None;
+            }
+
+            if head == tail {
+                let _ =
+                    self.tail
+                        .compare_exchange(tail, next, Ordering::Release, Ordering::Relaxed);
+                continue;
+            }
+
+            if self
+                .head
+                .compare_exchange(head, next, Ordering::AcqRel, Ordering::Relaxed)
+                .is_ok()
+            {
+                // Only the thread that advanced `head` past `next` may move its
+                // key out; `next` becomes the new dummy.
+                let key = unsafe { (*next).key.assume_init_read() };
+                unsafe { guard.retire(head) };
+                return Some(key);
+            }
+        }
+    }
+}
+
+impl<T, R: Reclaim + Default> Default for LockFreeQueue<T, R> {
+    fn default() -> Self {
+        Self::with_reclaimer(R::default())
+    }
+}
+
+impl<T, R> Drop for LockFreeQueue<T, R> {
+    fn drop(&mut self) {
+        let dummy = unsafe { Box::from_raw(self.head.load(Ordering::Relaxed)) };
+        let mut node = dummy.next.load(Ordering::Relaxed);
+
+        while !node.is_null() {
+            let mut boxed = unsafe { Box::from_raw(node) };
+            node = boxed.next.load(Ordering::Relaxed);
+            unsafe { boxed.key.assume_init_drop() };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    use super::*;
+    use crate::concurrent::reclaim::HazardPointers;
+    use crate::testing::{await_reclaimed, Tracked};
+
+    const ROUNDS: usize = 20;
+    const THREADS: usize = 4;
+    const PUSHES: usize = 10_000;
+
+    // Half the threads only push and the other half push and pop, so pops
+    // race both pushes and each other, and the queues are dropped with
+    // elements still in them.
+    fn push_and_pop<R: Reclaim + Default + Send + Sync>() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let popped = AtomicUsize::new(0);
+
+        for _ in 0..ROUNDS {
+            let queue = LockFreeQueue::<Tracked<R>, R>::default();
+            thread::scope(|scope| {
+                for thread in 0..THREADS {
+                    let (queue, drops, popped) = (&queue, &drops, &popped);
+                    scope.spawn(move || {
+                        for _ in 0..PUSHES {
+                            queue.push(Tracked::new(drops));
+                            if thread % 2 == 1 && queue.pop().is_some() {
+                                popped.fetch_add(1, Ordering::Relaxed);
+                            }
+                        }
+                    });
+                }
+            });
+        }
+
+        // Popped elements are dropped by their poppers, the rest by `Drop`.
+        assert_eq!(drops.load(Ordering::Relaxed), ROUNDS * THREADS * PUSHES);
+        // Every pop retires the old dummy, and every retired node is freed
+        // once.
+        assert_eq!(await_reclaimed::<Node<Tracked<R>>>(), popped.into_inner());
+    }
+
+    #[test]
+    fn epoch_frees_every_popped_node_once() {
+        push_and_pop::<Epoch>();
+    }
+
+    #[test]
+    fn hazard_pointers_frees_every_popped_node_once() {
+        push_and_pop::<HazardPointers>();
+    }
+}
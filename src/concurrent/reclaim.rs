@@ -0,0 +1,122 @@
+use super::sync::{AtomicPtr, Ordering};
+
+mod epoch;
+mod hazard;
+
+pub use epoch::EpochGuard;
+pub use hazard::HazardGuard;
+
+/// A strategy for freeing nodes that have been unlinked from a concurrent
+/// structure while other threads may still be reading them.
+///
+/// # Safety
+///
+/// A pointer returned by [`Guard::protect`] must stay allocated until the
+/// guard is dropped or the slot is reused, even if another thread retires it.
+pub unsafe trait Reclaim {
+    type Guard<'a>: Guard
+    where
+        Self: 'a;
+
+    fn pin(&self) -> Self::Guard<'_>;
+}
+
+/// # Safety
+///
+/// See [`Reclaim`].
+pub unsafe trait Guard {
+    /// Loads `src` and protects the result through `slot`, which must be
+    /// `0` or `1`. Protecting a new pointer through a slot releases the old one.
+    /// The pointer may carry a tag in its low bit, and then protects the
+    /// untagged one.
+    fn protect<T>(&self, slot: usize, src: &AtomicPtr<T>) -> *mut T;
+
+    /// Schedules `ptr` to be dropped as a `Box<T>` once no guard protects it.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must come from `Box::into_raw` and must already be unreachable
+    /// for guards pinned from now on, and it must be retired only once.
+    unsafe fn retire<T>(&self, ptr: *mut T);
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Epoch;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HazardPointers;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Leak;
+
+#[derive(Debug)]
+pub struct LeakGuard;
+
+unsafe impl Reclaim for Epoch {
+    type Guard<'a> = EpochGuard;
+
+    fn pin(&self) -> EpochGuard {
+        epoch::pin()
+    }
+}
+
+unsafe impl Reclaim for HazardPointers {
+    type Guard<'a> = HazardGuard;
+
+    fn pin(&self) -> HazardGuard {
+        hazard::pin()
+    }
+}
+
+unsafe impl Reclaim for Leak {
+    type Guard<'a> = LeakGuard;
+
+    fn pin(&self) -> LeakGuard {
+        LeakGuard
+    }
+}
+
+unsafe impl Guard for LeakGuard {
+    fn protect<T>(&self, _slot: usize, src: &AtomicPtr<T>) -> *mut T {
+        src.load(Ordering::Acquire)
+    }
+
+    unsafe fn retire<T>(&self, _ptr: *mut T) {}
+}
+
+// Frees whatever garbage the backends can: this thread's, and what exited
+// threads left behind.
+#[cfg(test)]
+pub(crate) fn flush() {
+    epoch::flush();
+    hazard::flush();
+}
+
+struct Deferred {
+    ptr: *mut (),
+    free: unsafe fn(*mut ()),
+}
+
+unsafe impl Send for Deferred {}
+
+impl Deferred {
+    fn new<T>(ptr: *mut T) -> Deferred {
+        unsafe fn free<T>(ptr: *mut ()) {
+            #[cfg(test)]
+            crate::testing::count_freed::<T>();
+            let _ = Box::from_raw(ptr as *mut T);
+        }
+
+        #[cfg(test)]
+        crate::testing::count_retired::<T>();
+
+        Deferred {
+            ptr: ptr as *mut (),
+            free: free::<T>,
+        }
+    }
+
+    unsafe fn call(self) {
+        (self.free)(self.ptr)
+    }
+}
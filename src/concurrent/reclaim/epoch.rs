@@ -0,0 +1,207 @@
+use std::{
+    cell::{Cell, RefCell},
+    marker::PhantomData,
+    mem, ptr,
+    sync::Mutex,
+};
+
+use super::{Deferred, Guard};
+use crate::{
+    atomic::{fence, AtomicBool, AtomicPtr, AtomicUsize, Ordering},
+    concurrent::sync,
+};
+
+// Epoch-based reclamation, the default backend of the concurrent types.
+//
+// A thread pins itself by publishing the global epoch it observed. Garbage
+// is stamped with the global epoch when it is retired and freed once the
+// global epoch has moved two steps past the stamp, since the epoch can only
+// advance when every pinned thread has observed the current one.
+
+const COLLECT_EVERY: usize = 64;
+
+static EPOCH: AtomicUsize = AtomicUsize::new(0);
+
+static PARTICIPANTS: AtomicPtr<Participant> = AtomicPtr::new(ptr::null_mut());
+
+static ORPHANS: Mutex<Vec<(usize, Deferred)>> = Mutex::new(Vec::new());
+
+struct Participant {
+    // `epoch << 1 | 1` while pinned, `0` otherwise.
+    state: AtomicUsize,
+    in_use: AtomicBool,
+    next: *mut Participant,
+}
+
+struct Local {
+    participant: &'static Participant,
+    pins: Cell<usize>,
+    bag: RefCell<Vec<(usize, Deferred)>>,
+}
+
+thread_local! {
+    static LOCAL: Local = Local::register();
+}
+
+pub struct EpochGuard {
+    marker: PhantomData<*mut ()>,
+}
+
+pub(super) fn pin() -> EpochGuard {
+    LOCAL.with(|local| {
+        let pins = local.pins.get();
+        if pins == 0 {
+            let epoch = EPOCH.load(Ordering::Relaxed);
+            local
+                .participant
+                .state
+                .store(epoch << 1 | 1, Ordering::Relaxed);
+            // Publishes the pin before any shared pointer is loaded.
+            fence(Ordering::SeqCst);
+        }
+        local.pins.set(pins + 1);
+    });
+
+    EpochGuard {
+        marker: PhantomData,
+    }
+}
+
+#[cfg(test)]
+pub(super) fn flush() {
+    LOCAL.with(Local::collect);
+}
+
+unsafe impl Guard for EpochGuard {
+    fn protect<T>(&self, _slot: usize, src: &sync::AtomicPtr<T>) -> *mut T {
+        src.load(Ordering::Acquire)
+    }
+
+    unsafe fn retire<T>(&self, ptr: *mut T) {
+        fence(Ordering::SeqCst);
+        let deferred = (EPOCH.load(Ordering::Relaxed), Deferred::new(ptr));
+
+        LOCAL.with(|local| {
+            let len = {
+                let mut bag = local.bag.borrow_mut();
+                bag.push(deferred);
+                bag.len()
+            };
+            if len % COLLECT_EVERY == 0 {
+                local.collect();
+            }
+        });
+    }
+}
+
+impl Drop for EpochGuard {
+    fn drop(&mut self) {
+        LOCAL.with(|local| {
+            let pins = local.pins.get() - 1;
+            local.pins.set(pins);
+            if pins == 0 {
+                local.participant.state.store(0, Ordering::Release);
+            }
+        });
+    }
+}
+
+impl Local {
+    fn register() -> Local {
+        let mut curr = PARTICIPANTS.load(Ordering::Acquire);
+        while let Some(participant) = unsafe { curr.as_ref() } {
+            if participant
+                .in_use
+                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+            {
+                return Local::new(participant);
+            }
+            curr = participant.next;
+        }
+
+        let participant = Box::into_raw(Box::new(Participant {
+            state: AtomicUsize::new(0),
+            in_use: AtomicBool::new(true),
+            next: ptr::null_mut(),
+        }));
+
+        let mut head = PARTICIPANTS.load(Ordering::Relaxed);
+        loop {
+            unsafe { (*participant).next = head };
+            match PARTICIPANTS.compare_exchange_weak(
+                head,
+                participant,
+                Ordering::Release,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(actual) => head = actual,
+            }
+        }
+
+        Local::new(unsafe { &*participant })
+    }
+
+    fn new(participant: &'static Participant) -> Local {
+        Local {
+            participant,
+            pins: Cell::new(0),
+            bag: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn collect(&self) {
+        let epoch = try_advance();
+
+        let mut bag = self.bag.borrow_mut();
+        if let Ok(mut orphans) = ORPHANS.try_lock() {
+            bag.append(&mut orphans);
+        }
+
+        let (expired, pending) = mem::take(&mut *bag)
+            .into_iter()
+            .partition::<Vec<_>, _>(|(stamp, _)| stamp + 2 <= epoch);
+        *bag = pending;
+        drop(bag);
+
+        for (_, deferred) in expired {
+            unsafe { deferred.call() };
+        }
+    }
+}
+
+impl Drop for Local {
+    fn drop(&mut self) {
+        let bag = mem::take(self.bag.get_mut());
+        if !bag.is_empty() {
+            ORPHANS
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .extend(bag);
+        }
+
+        self.participant.state.store(0, Ordering::Release);
+        self.participant.in_use.store(false, Ordering::Release);
+    }
+}
+
+fn try_advance() -> usize {
+    let epoch = EPOCH.load(Ordering::Relaxed);
+    fence(Ordering::SeqCst);
+
+    let mut curr = PARTICIPANTS.load(Ordering::Acquire);
+    while let Some(participant) = unsafe { curr.as_ref() } {
+        let state = participant.state.load(Ordering::Relaxed);
+        if state & 1 == 1 && state >> 1 != epoch {
+            return epoch;
+        }
+        curr = participant.next;
+    }
+
+    fence(Ordering::Acquire);
+    match EPOCH.compare_exchange(epoch, epoch + 1, Ordering::Release, Ordering::Relaxed) {
+        Ok(_) => epoch + 1,
+        Err(actual) => actual,
+    }
+}
@@ -0,0 +1,179 @@
+use std::{cell::RefCell, marker::PhantomData, mem, ptr, sync::Mutex};
+
+use super::{Deferred, Guard};
+use crate::{
+    atomic::{fence, AtomicBool, AtomicPtr, Ordering},
+    concurrent::sync,
+};
+
+// Hazard pointers: every guard owns a record whose slots announce the nodes
+// it is about to dereference. Retired nodes are freed by a scan once no slot
+// of any record points at them, so a stalled reader only keeps alive the
+// nodes it has announced.
+
+const SLOTS: usize = 2;
+
+const SCAN_EVERY: usize = 64;
+
+static RECORDS: AtomicPtr<Record> = AtomicPtr::new(ptr::null_mut());
+
+static ORPHANS: Mutex<Vec<Deferred>> = Mutex::new(Vec::new());
+
+struct Record {
+    slots: [AtomicPtr<()>; SLOTS],
+    in_use: AtomicBool,
+    next: *mut Record,
+}
+
+struct Retired {
+    bag: RefCell<Vec<Deferred>>,
+}
+
+thread_local! {
+    static RETIRED: Retired = const {
+        Retired {
+            bag: RefCell::new(Vec::new()),
+        }
+    };
+}
+
+pub struct HazardGuard {
+    record: &'static Record,
+    marker: PhantomData<*mut ()>,
+}
+
+pub(super) fn pin() -> HazardGuard {
+    HazardGuard {
+        record: Record::acquire(),
+        marker: PhantomData,
+    }
+}
+
+#[cfg(test)]
+pub(super) fn flush() {
+    RETIRED.with(Retired::scan);
+}
+
+unsafe impl Guard for HazardGuard {
+    fn protect<T>(&self, slot: usize, src: &sync::AtomicPtr<T>) -> *mut T {
+        let slot = &self.record.slots[slot];
+
+        let mut ptr = src.load(Ordering::Relaxed);
+        loop {
+            slot.store(ptr as *mut (), Ordering::Relaxed);
+            // Publishes the hazard before re-checking that `ptr` is still
+            // reachable, pairing with the fence in `scan`.
+            fence(Ordering::SeqCst);
+
+            let actual = src.load(Ordering::Acquire);
+            if actual == ptr {
+                return ptr;
+            }
+            ptr = actual;
+        }
+    }
+
+    unsafe fn retire<T>(&self, ptr: *mut T) {
+        RETIRED.with(|retired| {
+            let len = {
+                let mut bag = retired.bag.borrow_mut();
+                bag.push(Deferred::new(ptr));
+                bag.len()
+            };
+            if len % SCAN_EVERY == 0 {
+                retired.scan();
+            }
+        });
+    }
+}
+
+impl Drop for HazardGuard {
+    fn drop(&mut self) {
+        for slot in &self.record.slots {
+            slot.store(ptr::null_mut(), Ordering::Release);
+        }
+        self.record.in_use.store(false, Ordering::Release);
+    }
+}
+
+impl Record {
+    fn acquire() -> &'static Record {
+        let mut curr = RECORDS.load(Ordering::Acquire);
+        while let Some(record) = unsafe { curr.as_ref() } {
+            if record
+                .in_use
+                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+            {
+                return record;
+            }
+            curr = record.next;
+        }
+
+        let record = Box::into_raw(Box::new(Record {
+            slots: [const { AtomicPtr::new(ptr::null_mut()) }; SLOTS],
+            in_use: AtomicBool::new(true),
+            next: ptr::null_mut(),
+        }));
+
+        let mut head = RECORDS.load(Ordering::Relaxed);
+        loop {
+            unsafe { (*record).next = head };
+            match RECORDS.compare_exchange_weak(head, record, Ordering::Release, Ordering::Relaxed)
+            {
+                Ok(_) => break,
+                Err(actual) => head = actual,
+            }
+        }
+
+        unsafe { &*record }
+    }
+}
+
+impl Retired {
+    fn scan(&self) {
+        fence(Ordering::SeqCst);
+
+        let mut hazards = Vec::new();
+        let mut curr = RECORDS.load(Ordering::Acquire);
+        while let Some(record) = unsafe { curr.as_ref() } {
+            for slot in &record.slots {
+                // A slot may hold a link with its low tag bit set; it
+                // protects the node it points into all the same.
+                let ptr = slot.load(Ordering::Acquire).map_addr(|addr| addr & !1);
+                if !ptr.is_null() {
+                    hazards.push(ptr);
+                }
+            }
+            curr = record.next;
+        }
+        hazards.sort_unstable();
+
+        let mut bag = self.bag.borrow_mut();
+        if let Ok(mut orphans) = ORPHANS.try_lock() {
+            bag.append(&mut orphans);
+        }
+
+        let (protected, expired) = mem::take(&mut *bag)
+            .into_iter()
+            .partition::<Vec<_>, _>(|deferred| hazards.binary_search(&deferred.ptr).is_ok());
+        *bag = protected;
+        drop(bag);
+
+        for deferred in expired {
+            unsafe { deferred.call() };
+        }
+    }
+}
+
+impl Drop for Retired {
+    fn drop(&mut self) {
+        let bag = mem::take(self.bag.get_mut());
+        if !bag.is_empty() {
+            ORPHANS
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .extend(bag);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use alloc::boxed::Box;
+use core::{cell::UnsafeCell, mem::MaybeUninit};
+
+use super::sync::{Arc, AtomicUsize, Ordering};
+
+// Keeps the two indices on separate cache lines, so the producer and the
+// consumer do not invalidate each other's line on every operation.
+#[repr(align(64))]
+struct Padded<T>(T);
+
+// Lamport's ring: the producer alone moves `tail` and the consumer alone
+// moves `head`, so every operation is a bounded number of loads and stores.
+// Each side also caches the other's index and only reloads it when the ring
+// looks full or empty. Indices grow without wrapping at the capacity; the
+// capacity is a power of two, so masking them finds the slot.
+struct Ring<T> {
+    head: Padded<AtomicUsize>,
+    tail: Padded<AtomicUsize>,
+    slots: Box<[UnsafeCell<MaybeUninit<T>>]>,
+}
+
+unsafe impl<T: Send> Send for Ring<T> {}
+
+unsafe impl<T: Send> Sync for Ring<T> {}
+
+impl<T> Ring<T> {
+    fn slot(&self, at: usize) -> *mut MaybeUninit<T> {
+        self.slots[at & (self.slots.len() - 1)].get()
+    }
+}
+
+impl<T> Drop for Ring<T> {
+    fn drop(&mut self) {
+        let mut at = self.head.0.load(Ordering::Relaxed);
+        let tail = self.tail.0.load(Ordering::Relaxed);
+
+        while at != tail {
+            unsafe { (*self.slot(at)).assume_init_drop() };
+            at = at.wrapping_add(1);
+        }
+    }
+}
+
+pub struct SpscQueue<T> {
+    ring: Ring<T>,
+}
+
+pub struct Producer<T> {
+    ring: Arc<Ring<T>>,
+    tail: usize,
+    head: usize,
+}
+
+pub struct Consumer<T> {
+    ring: Arc<Ring<T>>,
+    head: usize,
+    tail: usize,
+}
+
+impl<T> SpscQueue<T> {
+    // Rounds `capacity` up to a power of two.
+    pub fn with_capacity(capacity: usize) -> SpscQueue<T> {
+        assert!(capacity != 0, "Capacity must be non-zero");
+
+        let slots = (0..capacity.next_power_of_two())
+            .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+            .collect();
+
+        SpscQueue {
+            ring: Ring {
+                head: Padded(AtomicUsize::new(0)),
+                tail: Padded(AtomicUsize::new(0)),
+                slots,
+            },
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.ring.slots.len()
+    }
+
+    pub fn split(self) -> (Producer<T>, Consumer<T>) {
+        let ring = Arc::new(self.ring);
+
+        let producer = Producer {
+            ring: ring.clone(),
+            tail: 0,
+            head: 0,
+        };
+        let consumer = Consumer {
+            ring,
+            head: 0,
+            tail: 0,
+        };
+
+        (producer, consumer)
+    }
+}
+
+impl<T> Producer<T> {
+    pub fn capacity(&self) -> usize {
+        self.ring.slots.len()
+    }
+
+    // Hands `key` back if the ring is full.
+    pub fn push(&mut self, key: T) -> Result<(), T> {
+        if self.tail.wrapping_sub(self.head) == self.capacity() {
+            self.head = self.ring.head.0.load(Ordering::Acquire);
+            if self.tail.wrapping_sub(self.head) == self.capacity() {
+                return Err(key);
+            }
+        }
+
+        unsafe { (*self.ring.slot(self.tail)).write(key) };
+        self.tail = self.tail.wrapping_add(1);
+        self.ring.tail.0.store(self.tail, Ordering::Release);
+
+        Ok(())
+    }
+}
+
+impl<T> Consumer<T> {
+    pub fn capacity(&self) -> usize {
+        self.ring.slots.len()
+    }
+
+    pub fn len(&self) -> usize {
+        let tail = self.ring.tail.0.load(Ordering::Acquire);
+        tail.wrapping_sub(self.head)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.head == self.tail {
+            self.tail = self.ring.tail.0.load(Ordering::Acquire);
+            if self.head == self.tail {
+                return None;
+            }
+        }
+
+        let key = unsafe { (*self.ring.slot(self.head)).assume_init_read() };
+        self.head = self.head.wrapping_add(1);
+        self.ring.head.0.store(self.head, Ordering::Release);
+
+        Some(key)
+    }
+}
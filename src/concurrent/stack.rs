@@ -0,0 +1,298 @@
+use core::{iter::FusedIterator, marker::PhantomData, mem::ManuallyDrop, ptr};
+
+use super::{
+    reclaim::{Epoch, Guard, Reclaim},
+    sync::{AtomicPtr, Ordering},
+};
+
+struct Node<T> {
+    key: ManuallyDrop<T>,
+    next: AtomicPtr<Node<T>>,
+}
+
+// The low bit of a link tags it. A tagged `head` means its node is being
+// popped, and a node's tagged `next` means the node has left the stack.
+const TAG: usize = 1;
+
+fn tagged<T>(ptr: *mut T) -> *mut T {
+    ptr.map_addr(|addr| addr | TAG)
+}
+
+fn untagged<T>(ptr: *mut T) -> *mut T {
+    ptr.map_addr(|addr| addr & !TAG)
+}
+
+fn is_tagged<T>(ptr: *mut T) -> bool {
+    ptr.addr() & TAG != 0
+}
+
+pub struct LockFreeStack<T, R = Epoch> {
+    head: AtomicPtr<Node<T>>,
+    reclaimer: R,
+}
+
+unsafe impl<T: Send, R: Send> Send for LockFreeStack<T, R> {}
+
+unsafe impl<T: Send, R: Sync> Sync for LockFreeStack<T, R> {}
+
+// loom's atomics cannot be built in a const context.
+#[cfg(not(loom))]
+impl<T> LockFreeStack<T> {
+    pub const fn new() -> LockFreeStack<T> {
+        LockFreeStack::with_reclaimer(Epoch)
+    }
+}
+
+#[cfg(loom)]
+impl<T> LockFreeStack<T> {
+    pub fn new() -> LockFreeStack<T> {
+        LockFreeStack::with_reclaimer(Epoch)
+    }
+}
+
+#[cfg(not(loom))]
+impl<T, R: Reclaim> LockFreeStack<T, R> {
+    pub const fn with_reclaimer(reclaimer: R) -> LockFreeStack<T, R> {
+        LockFreeStack {
+            head: AtomicPtr::new(ptr::null_mut()),
+            reclaimer,
+        }
+    }
+}
+
+#[cfg(loom)]
+impl<T, R: Reclaim> LockFreeStack<T, R> {
+    pub fn with_reclaimer(reclaimer: R) -> LockFreeStack<T, R> {
+        LockFreeStack {
+            head: AtomicPtr::new(ptr::null_mut()),
+            reclaimer,
+        }
+    }
+}
+
+impl<T, R: Reclaim> LockFreeStack<T, R> {
+    pub fn is_empty(&self) -> bool {
+        self.head.load(Ordering::Acquire).is_null()
+    }
+
+    pub fn push(&self, key: T) {
+        let node = Box::into_raw(Box::new(Node {
+            key: ManuallyDrop::new(key),
+            next: AtomicPtr::new(ptr::null_mut()),
+        }));
+
+        let next = unsafe { &(*node).next };
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            // A pop is halfway through; it has to finish before anything can
+            // go on top.
+            if is_tagged(head) {
+                let guard = self.reclaimer.pin();
+                let head = guard.protect(0, &self.head);
+                if is_tagged(head) {
+                    unsafe { self.finish_pop(untagged(head)) };
+                }
+            } else {
+                next.store(head, Ordering::Relaxed);
+                if self
+                    .head
+                    .compare_exchange_weak(head, node, Ordering::Release, Ordering::Relaxed)
+                    .is_ok()
+                {
+                    return;
+                }
+            }
+            head = self.head.load(Ordering::Relaxed);
+        }
+    }
+
+    pub fn pop(&self) -> Option<T> {
+        let guard = self.reclaimer.pin();
+
+        let head = loop {
+            let head = guard.protect(0, &self.head);
+            if head.is_null() {
+                return None;
+            }
+
+            if is_tagged(head) {
+                unsafe { self.finish_pop(untagged(head)) };
+                continue;
+            }
+
+            if self
+                .head
+                .compare_exchange(head, tagged(head), Ordering::AcqRel, Ordering::Relaxed)
+                .is_ok()
+            {
+                unsafe { self.finish_pop(head) };
+                break head;
+            }
+        };
+
+        // Read through a shared reference: iterators may be copying the
+        // element at the same time.
+        let key = ManuallyDrop::into_inner(unsafe { ptr::read(&(*head).key) });
+        unsafe { guard.retire(head) };
+
+        Some(key)
+    }
+
+    // Completes the removal of `head`, which the caller protects and which
+    // `self.head` holds tagged or has already moved past: the node's own link
+    // is tagged first, then `self.head` moves on to its successor. Any thread
+    // that sees the tagged head can do this, so a stalled pop blocks no one.
+    unsafe fn finish_pop(&self, head: *mut Node<T>) {
+        let link = &(*head).next;
+        let mut next = link.load(Ordering::Acquire);
+        if !is_tagged(next) {
+            if let Err(actual) =
+                link.compare_exchange(next, tagged(next), Ordering::AcqRel, Ordering::Acquire)
+            {
+                next = actual;
+            }
+        }
+
+        let _ = self.head.compare_exchange(
+            tagged(head),
+            untagged(next),
+            Ordering::AcqRel,
+            Ordering::Relaxed,
+        );
+    }
+
+    // Walks the stack from the top while other threads push and pop. Each
+    // node is protected before it is read, and the walk stops early once it
+    // reaches a node that has been popped, since that node's link no longer
+    // leads into the stack. Elements are copied out: a popped element is
+    // moved out by its popper and may be gone by the time it is read. The
+    // popper reads it at the same time, hence `Sync`.
+    pub fn iter(&self) -> StackIter<'_, T, R>
+    where
+        T: Copy + Sync,
+    {
+        let guard = self.reclaimer.pin();
+
+        let node = loop {
+            let head = guard.protect(0, &self.head);
+            if !is_tagged(head) {
+                break head;
+            }
+            unsafe { self.finish_pop(untagged(head)) };
+        };
+
+        StackIter {
+            guard,
+            node,
+            slot: 0,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, R: Reclaim + Default> Default for LockFreeStack<T, R> {
+    fn default() -> Self {
+        Self::with_reclaimer(R::default())
+    }
+}
+
+pub struct StackIter<'a, T, R: Reclaim + 'a> {
+    guard: R::Guard<'a>,
+    node: *mut Node<T>,
+    slot: usize,
+    marker: PhantomData<&'a LockFreeStack<T, R>>,
+}
+
+// Hand over hand: the next node is protected through the slot the current
+// one is not using before the current one is let go.
+impl<T: Copy + Sync, R: Reclaim> Iterator for StackIter<'_, T, R> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.node.is_null() {
+            return None;
+        }
+
+        let key = unsafe { *(*self.node).key };
+
+        let slot = 1 - self.slot;
+        let next = self.guard.protect(slot, unsafe { &(*self.node).next });
+        if is_tagged(next) {
+            self.node = ptr::null_mut();
+        } else {
+            self.node = next;
+            self.slot = slot;
+        }
+
+        Some(key)
+    }
+}
+
+impl<T: Copy + Sync, R: Reclaim> FusedIterator for StackIter<'_, T, R> {}
+
+impl<T, R> Drop for LockFreeStack<T, R> {
+    fn drop(&mut self) {
+        let mut node = self.head.load(Ordering::Relaxed);
+        while !node.is_null() {
+            let mut boxed = unsafe { Box::from_raw(node) };
+            node = boxed.next.load(Ordering::Relaxed);
+            unsafe { ManuallyDrop::drop(&mut boxed.key) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    use super::*;
+    use crate::concurrent::reclaim::HazardPointers;
+    use crate::testing::{await_reclaimed, Tracked};
+
+    const ROUNDS: usize = 20;
+    const THREADS: usize = 4;
+    const PUSHES: usize = 10_000;
+
+    // Each thread pushes its keys and pops after two in three of them, so
+    // the stacks are dropped with elements still in them.
+    fn push_and_pop<R: Reclaim + Default + Send + Sync>() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let popped = AtomicUsize::new(0);
+
+        for _ in 0..ROUNDS {
+            let stack = LockFreeStack::<Tracked<R>, R>::default();
+            thread::scope(|scope| {
+                for _ in 0..THREADS {
+                    scope.spawn(|| {
+                        for key in 0..PUSHES {
+                            stack.push(Tracked::new(&drops));
+                            if key % 3 != 0 && stack.pop().is_some() {
+                                popped.fetch_add(1, Ordering::Relaxed);
+                            }
+                        }
+                    });
+                }
+            });
+        }
+
+        // Popped elements are dropped by their poppers, the rest by `Drop`.
+        assert_eq!(drops.load(Ordering::Relaxed), ROUNDS * THREADS * PUSHES);
+        // Every pop retires its node, and every retired node is freed once.
+        assert_eq!(await_reclaimed::<Node<Tracked<R>>>(), popped.into_inner());
+    }
+
+    #[test]
+    fn epoch_frees_every_popped_node_once() {
+        push_and_pop::<Epoch>();
+    }
+
+    #[test]
+    fn hazard_pointers_frees_every_popped_node_once() {
+        push_and_pop::<HazardPointers>();
+    }
+}
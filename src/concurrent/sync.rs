@@ -0,0 +1,21 @@
+// The atomics shared by the lock-free structures and the `Guard` interface,
+// and the mutex and yield `RcuList` waits with. Building with `--cfg loom`
+// swaps in loom's, so the model checker can explore every interleaving of
+// their operations.
+
+#[cfg(all(loom, feature = "std"))]
+pub(crate) use loom::sync::atomic::{fence, AtomicIsize};
+#[cfg(loom)]
+pub(crate) use loom::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+#[cfg(all(loom, feature = "std"))]
+pub(crate) use loom::{sync::Mutex, thread::yield_now};
+
+#[cfg(all(not(loom), feature = "std"))]
+pub(crate) use crate::atomic::{fence, AtomicIsize};
+#[cfg(not(loom))]
+pub(crate) use crate::atomic::{AtomicPtr, AtomicUsize, Ordering};
+#[cfg(all(not(loom), feature = "std"))]
+pub(crate) use std::{sync::Mutex, thread::yield_now};
+
+// The queues' shared ends are not modelled, so this is never loom's.
+pub(crate) use crate::atomic::Arc;
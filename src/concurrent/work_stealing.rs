@@ -0,0 +1,352 @@
+use core::{
+    cell::{Cell, UnsafeCell},
+    marker::PhantomData,
+    mem::MaybeUninit,
+};
+
+use super::{
+    reclaim::{Epoch, Guard, Reclaim},
+    sync::{fence, Arc, AtomicIsize, AtomicPtr, Ordering},
+};
+
+const MIN_CAPACITY: usize = 16;
+
+struct Buffer<T> {
+    slots: Box<[UnsafeCell<MaybeUninit<T>>]>,
+}
+
+impl<T> Buffer<T> {
+    fn alloc(capacity: usize) -> *mut Buffer<T> {
+        let slots = (0..capacity)
+            .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+            .collect();
+        Box::into_raw(Box::new(Buffer { slots }))
+    }
+
+    fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    // The capacity is a power of two, so the mask wraps any index.
+    fn slot(&self, at: isize) -> *mut MaybeUninit<T> {
+        self.slots[at as usize & (self.capacity() - 1)].get()
+    }
+
+    unsafe fn write(&self, at: isize, key: T) {
+        (*self.slot(at)).write(key);
+    }
+
+    // Copied out untyped: a thief's copy is only a speculation until its CAS
+    // succeeds, and must not touch the element before then.
+    unsafe fn read(&self, at: isize) -> MaybeUninit<T> {
+        self.slot(at).read()
+    }
+}
+
+// Chase–Lev, with the orderings of Lê et al.: the owner pushes and pops at
+// `bottom`, thieves take from `top`, and the two sides only race over the
+// last element, which a CAS on `top` settles. A thief reads its element
+// before that CAS and discards the copy if it loses.
+struct Inner<T, R> {
+    top: AtomicIsize,
+    bottom: AtomicIsize,
+    buffer: AtomicPtr<Buffer<T>>,
+    reclaimer: R,
+}
+
+unsafe impl<T: Send, R: Send> Send for Inner<T, R> {}
+
+unsafe impl<T: Send, R: Sync> Sync for Inner<T, R> {}
+
+impl<T, R> Drop for Inner<T, R> {
+    fn drop(&mut self) {
+        let top = self.top.load(Ordering::Relaxed);
+        let bottom = self.bottom.load(Ordering::Relaxed);
+        let buffer = unsafe { Box::from_raw(self.buffer.load(Ordering::Relaxed)) };
+
+        for at in top..bottom {
+            unsafe { buffer.read(at).assume_init_drop() };
+        }
+    }
+}
+
+// The owner's end. It can be sent to another thread but not shared, since
+// only one thread may push and pop; thieves go through `Stealer`s.
+pub struct WorkStealingDeque<T, R = Epoch> {
+    inner: Arc<Inner<T, R>>,
+    marker: PhantomData<Cell<()>>,
+}
+
+pub struct Stealer<T, R = Epoch> {
+    inner: Arc<Inner<T, R>>,
+}
+
+impl<T> WorkStealingDeque<T> {
+    pub fn new() -> WorkStealingDeque<T> {
+        WorkStealingDeque::with_reclaimer(Epoch)
+    }
+}
+
+impl<T, R: Reclaim> WorkStealingDeque<T, R> {
+    pub fn with_reclaimer(reclaimer: R) -> WorkStealingDeque<T, R> {
+        WorkStealingDeque {
+            inner: Arc::new(Inner {
+                top: AtomicIsize::new(0),
+                bottom: AtomicIsize::new(0),
+                buffer: AtomicPtr::new(Buffer::alloc(MIN_CAPACITY)),
+                reclaimer,
+            }),
+            marker: PhantomData,
+        }
+    }
+
+    pub fn stealer(&self) -> Stealer<T, R> {
+        Stealer {
+            inner: self.inner.clone(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        let bottom = self.inner.bottom.load(Ordering::Relaxed);
+        let top = self.inner.top.load(Ordering::Acquire);
+        (bottom - top).max(0) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn push(&self, key: T) {
+        let inner = &*self.inner;
+        let bottom = inner.bottom.load(Ordering::Relaxed);
+        let top = inner.top.load(Ordering::Acquire);
+        let mut buffer = inner.buffer.load(Ordering::Relaxed);
+
+        if (bottom - top) as usize >= unsafe { (*buffer).capacity() } {
+            buffer = unsafe { self.grow(buffer, top, bottom) };
+        }
+
+        unsafe { (*buffer).write(bottom, key) };
+        fence(Ordering::Release);
+        inner.bottom.store(bottom + 1, Ordering::Relaxed);
+    }
+
+    // Copies the live range into a buffer twice the size. Thieves may still
+    // be reading the old one, so it is retired rather than freed.
+    unsafe fn grow(&self, old: *mut Buffer<T>, top: isize, bottom: isize) -> *mut Buffer<T> {
+        let buffer = Buffer::alloc((*old).capacity() * 2);
+        for at in top..bottom {
+            (*buffer).slot(at).write((*old).read(at));
+        }
+
+        let guard = self.inner.reclaimer.pin();
+        self.inner.buffer.store(buffer, Ordering::Release);
+        guard.retire(old);
+
+        buffer
+    }
+
+    pub fn pop(&self) -> Option<T> {
+        let inner = &*self.inner;
+        let bottom = inner.bottom.load(Ordering::Relaxed) - 1;
+        let buffer = inner.buffer.load(Ordering::Relaxed);
+        inner.bottom.store(bottom, Ordering::Relaxed);
+        fence(Ordering::SeqCst);
+        let top = inner.top.load(Ordering::Relaxed);
+
+        if top > bottom {
+            inner.bottom.store(bottom + 1, Ordering::Relaxed);
+            return None;
+        }
+
+        let key = unsafe { (*buffer).read(bottom) };
+        if top < bottom {
+            return Some(unsafe { key.assume_init() });
+        }
+
+        // The last element: a thief may be taking it at the same time.
+        let won = inner
+            .top
+            .compare_exchange(top, top + 1, Ordering::SeqCst, Ordering::Relaxed)
+            .is_ok();
+        inner.bottom.store(bottom + 1, Ordering::Relaxed);
+
+        won.then(|| unsafe { key.assume_init() })
+    }
+}
+
+impl<T, R: Reclaim + Default> Default for WorkStealingDeque<T, R> {
+    fn default() -> Self {
+        Self::with_reclaimer(R::default())
+    }
+}
+
+impl<T, R: Reclaim> Stealer<T, R> {
+    pub fn is_empty(&self) -> bool {
+        let top = self.inner.top.load(Ordering::Acquire);
+        fence(Ordering::SeqCst);
+        let bottom = self.inner.bottom.load(Ordering::Acquire);
+        top >= bottom
+    }
+
+    // Takes the element at the owner's far end. Losing the race for it to
+    // another thief or the owner is retried; `None` means the deque was seen
+    // empty.
+    pub fn steal(&self) -> Option<T> {
+        let inner = &*self.inner;
+        let guard = inner.reclaimer.pin();
+
+        loop {
+            let top = inner.top.load(Ordering::Acquire);
+            fence(Ordering::SeqCst);
+            let bottom = inner.bottom.load(Ordering::Acquire);
+            if top >= bottom {
+                return None;
+            }
+
+            let buffer = guard.protect(0, &inner.buffer);
+            let key = unsafe { (*buffer).read(top) };
+            if inner
+                .top
+                .compare_exchange(top, top + 1, Ordering::SeqCst, Ordering::Relaxed)
+                .is_ok()
+            {
+                return Some(unsafe { key.assume_init() });
+            }
+        }
+    }
+}
+
+impl<T, R> Clone for Stealer<T, R> {
+    fn clone(&self) -> Self {
+        Stealer {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        hint,
+        sync::{
+            atomic::{AtomicBool, AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    use super::*;
+    use crate::concurrent::reclaim::HazardPointers;
+    use crate::testing::{await_reclaimed, Tracked};
+
+    const ROUNDS: usize = 20;
+    const THIEVES: usize = 3;
+    const PUSHES: usize = 20_000;
+
+    // The owner pushes in bursts and pops after every other element while
+    // thieves steal, so the buffer grows under the thieves and the deques
+    // are dropped with elements still in them.
+    fn push_pop_and_steal<R: Reclaim + Default + Send + Sync>() {
+        let drops = Arc::new(AtomicUsize::new(0));
+
+        for _ in 0..ROUNDS {
+            let deque = WorkStealingDeque::<Tracked<R>, R>::default();
+            // Past `MIN_CAPACITY` before any thief runs, so every round
+            // retires at least one buffer.
+            for _ in 0..4 * MIN_CAPACITY {
+                deque.push(Tracked::new(&drops));
+            }
+
+            thread::scope(|scope| {
+                for _ in 0..THIEVES {
+                    let stealer = deque.stealer();
+                    scope.spawn(move || {
+                        for _ in 0..PUSHES / THIEVES {
+                            drop(stealer.steal());
+                        }
+                    });
+                }
+
+                for key in 0..PUSHES {
+                    deque.push(Tracked::new(&drops));
+                    if key % 2 == 1 {
+                        drop(deque.pop());
+                    }
+                }
+            });
+        }
+
+        let pushes = ROUNDS * (4 * MIN_CAPACITY + PUSHES);
+        // Popped and stolen elements are dropped at once, the rest by `Drop`.
+        assert_eq!(drops.load(Ordering::Relaxed), pushes);
+        // Every outgrown buffer is retired, and freed once.
+        assert!(await_reclaimed::<Buffer<Tracked<R>>>() >= ROUNDS);
+    }
+
+    #[test]
+    fn epoch_frees_every_outgrown_buffer_once() {
+        push_pop_and_steal::<Epoch>();
+    }
+
+    #[test]
+    fn hazard_pointers_frees_every_outgrown_buffer_once() {
+        push_pop_and_steal::<HazardPointers>();
+    }
+
+    // Thieves steal from the first push on, so the buffer grows past
+    // `MIN_CAPACITY` while they read from it. Every key must come out
+    // exactly once, either popped or stolen.
+    #[test]
+    fn growing_under_thieves_hands_out_each_key_once() {
+        const KEYS: usize = 64 * MIN_CAPACITY;
+        let mut grown = false;
+
+        for _ in 0..ROUNDS {
+            let deque = WorkStealingDeque::new();
+            let done = AtomicBool::new(false);
+
+            let mut taken = thread::scope(|scope| {
+                let thieves: Vec<_> = (0..THIEVES)
+                    .map(|_| {
+                        let (stealer, done) = (deque.stealer(), &done);
+                        scope.spawn(move || {
+                            let mut stolen = Vec::new();
+                            loop {
+                                match stealer.steal() {
+                                    Some(key) => stolen.push(key),
+                                    None if done.load(Ordering::Acquire) => return stolen,
+                                    None => hint::spin_loop(),
+                                }
+                            }
+                        })
+                    })
+                    .collect();
+
+                let mut popped = Vec::new();
+                for key in 0..KEYS {
+                    deque.push(key);
+                    if key % 8 == 7 {
+                        popped.extend(deque.pop());
+                    }
+                }
+                grown |= unsafe { (*deque.inner.buffer.load(Ordering::Relaxed)).capacity() }
+                    > MIN_CAPACITY;
+                while let Some(key) = deque.pop() {
+                    popped.push(key);
+                }
+                done.store(true, Ordering::Release);
+
+                for thief in thieves {
+                    popped.extend(thief.join().unwrap());
+                }
+                popped
+            });
+
+            taken.sort_unstable();
+            assert!(taken.iter().copied().eq(0..KEYS));
+        }
+
+        assert!(grown);
+    }
+}
@@ -0,0 +1,810 @@
+use core::{mem, ptr::NonNull};
+
+use crate::{LinkedList, Node};
+
+// A position in a list that can move both ways, modelled on std's
+// `linked_list::Cursor`. Past either end the cursor sits on a "ghost"
+// element that joins the tail back to the head, so moving on from the
+// ghost wraps around. `index` is the position of `current`, or `len` on the
+// ghost.
+pub struct Cursor<'a, T> {
+    index: usize,
+    current: Option<NonNull<Node<T>>>,
+    list: &'a LinkedList<T>,
+}
+
+// Like `Cursor`, but able to edit the list around the position it is at.
+pub struct CursorMut<'a, T> {
+    index: usize,
+    current: Option<NonNull<Node<T>>>,
+    list: &'a mut LinkedList<T>,
+}
+
+unsafe impl<T: Sync> Send for Cursor<'_, T> {}
+
+unsafe impl<T: Sync> Sync for Cursor<'_, T> {}
+
+unsafe impl<T: Send> Send for CursorMut<'_, T> {}
+
+unsafe impl<T: Sync> Sync for CursorMut<'_, T> {}
+
+impl<T> Clone for Cursor<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Cursor<'_, T> {}
+
+impl<T> LinkedList<T> {
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor {
+            index: 0,
+            current: self.head,
+            list: self,
+        }
+    }
+
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        Cursor {
+            index: self.len.saturating_sub(1),
+            current: self.tail,
+            list: self,
+        }
+    }
+
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            index: 0,
+            current: self.head,
+            list: self,
+        }
+    }
+
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            index: self.len.saturating_sub(1),
+            current: self.tail,
+            list: self,
+        }
+    }
+
+    // Links all of `other` in between `prev` and `next`, which must be
+    // neighbours in this list, with `None` standing for its ends.
+    unsafe fn splice_between(
+        &mut self,
+        prev: Option<NonNull<Node<T>>>,
+        next: Option<NonNull<Node<T>>>,
+        other: &mut LinkedList<T>,
+    ) {
+        let (Some(mut head), Some(mut tail)) = (other.head.take(), other.tail.take()) else {
+            return;
+        };
+
+        head.as_mut().prev = prev;
+        match prev {
+            Some(mut prev) => prev.as_mut().next = Some(head),
+            None => self.head = Some(head),
+        }
+        tail.as_mut().next = next;
+        match next {
+            Some(mut next) => next.as_mut().prev = Some(tail),
+            None => self.tail = Some(tail),
+        }
+
+        self.len += mem::replace(&mut other.len, 0);
+        self.version += 1;
+        other.version += 1;
+    }
+
+    // The node at `target`, or `None` for `len`, walked to from whichever is
+    // closest: `current`, the node at `index` if there is one, or an end.
+    fn seek(
+        &self,
+        current: Option<NonNull<Node<T>>>,
+        index: usize,
+        target: usize,
+    ) -> Option<NonNull<Node<T>>> {
+        if target == self.len {
+            return None;
+        }
+
+        let from_ends = target.min(self.len - 1 - target);
+        match current {
+            Some(mut node) if index.abs_diff(target) < from_ends => {
+                traversal_span!("seek", index.abs_diff(target));
+                for _ in target..index {
+                    node = unsafe { node.as_ref().prev.unwrap_unchecked() };
+                }
+                for _ in index..target {
+                    node = unsafe { node.as_ref().next.unwrap_unchecked() };
+                }
+                Some(node)
+            }
+            _ => {
+                traversal_span!("seek", from_ends);
+                Some(self.node_at(target))
+            }
+        }
+    }
+
+    // Detaches the nodes behind `node`, which must be the node at index
+    // `at`, and returns them as a list.
+    unsafe fn split_after(&mut self, mut node: NonNull<Node<T>>, at: usize) -> LinkedList<T> {
+        let Some(mut next) = node.as_ref().next else {
+            return LinkedList::new();
+        };
+
+        node.as_mut().next = None;
+        next.as_mut().prev = None;
+
+        let mut back = LinkedList::new();
+        back.head = Some(next);
+        back.tail = self.tail.replace(node);
+        back.len = self.len - at - 1;
+
+        self.len = at + 1;
+        self.version += 1;
+
+        back
+    }
+}
+
+impl<'a, T> Cursor<'a, T> {
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(node) => {
+                self.current = unsafe { node.as_ref() }.next;
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(node) => {
+                self.current = unsafe { node.as_ref() }.prev;
+                self.index = match self.current {
+                    Some(_) => self.index - 1,
+                    None => self.list.len,
+                };
+            }
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+
+    // Jumps to the element at `at`, or to the ghost for `len`, walking from
+    // the cursor or from an end, whichever is closer.
+    pub fn seek_to(&mut self, at: usize) {
+        assert!(
+            at <= self.list.len,
+            "Index out of bound: len is `{}` but index is `{}`",
+            self.list.len,
+            at
+        );
+
+        self.current = self.list.seek(self.current, self.index, at);
+        self.index = at;
+    }
+
+    // Moves `n` elements towards the back, or as far as the ghost, and
+    // returns how many it moved. These two do not wrap around: the ghost
+    // counts as the position past the back.
+    pub fn advance_by(&mut self, n: usize) -> usize {
+        let at = self.index.saturating_add(n).min(self.list.len);
+        let moved = at - self.index;
+        self.seek_to(at);
+        moved
+    }
+
+    pub fn retreat_by(&mut self, n: usize) -> usize {
+        let at = self.index.saturating_sub(n);
+        let moved = self.index - at;
+        self.seek_to(at);
+        moved
+    }
+
+    // `None` on the ghost.
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    pub fn current(&self) -> Option<&'a T> {
+        self.current.map(|node| &unsafe { &*node.as_ptr() }.key)
+    }
+
+    // From the ghost, the next element is the front and the previous one the
+    // back; from the ends, the next or previous is the ghost.
+    pub fn peek_next(&self) -> Option<&'a T> {
+        let next = match self.current {
+            Some(node) => unsafe { node.as_ref() }.next,
+            None => self.list.head,
+        };
+        next.map(|node| &unsafe { &*node.as_ptr() }.key)
+    }
+
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        let prev = match self.current {
+            Some(node) => unsafe { node.as_ref() }.prev,
+            None => self.list.tail,
+        };
+        prev.map(|node| &unsafe { &*node.as_ptr() }.key)
+    }
+
+    pub fn front(&self) -> Option<&'a T> {
+        self.list.front()
+    }
+
+    pub fn back(&self) -> Option<&'a T> {
+        self.list.back()
+    }
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(node) => {
+                self.current = unsafe { node.as_ref() }.next;
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(node) => {
+                self.current = unsafe { node.as_ref() }.prev;
+                self.index = match self.current {
+                    Some(_) => self.index - 1,
+                    None => self.list.len,
+                };
+            }
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+
+    pub fn seek_to(&mut self, at: usize) {
+        assert!(
+            at <= self.list.len,
+            "Index out of bound: len is `{}` but index is `{}`",
+            self.list.len,
+            at
+        );
+
+        self.current = self.list.seek(self.current, self.index, at);
+        self.index = at;
+    }
+
+    pub fn advance_by(&mut self, n: usize) -> usize {
+        let at = self.index.saturating_add(n).min(self.list.len);
+        let moved = at - self.index;
+        self.seek_to(at);
+        moved
+    }
+
+    pub fn retreat_by(&mut self, n: usize) -> usize {
+        let at = self.index.saturating_sub(n);
+        let moved = self.index - at;
+        self.seek_to(at);
+        moved
+    }
+
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current
+            .map(|node| &mut unsafe { &mut *node.as_ptr() }.key)
+    }
+
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = match self.current {
+            Some(node) => unsafe { node.as_ref() }.next,
+            None => self.list.head,
+        };
+        next.map(|node| &mut unsafe { &mut *node.as_ptr() }.key)
+    }
+
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let prev = match self.current {
+            Some(node) => unsafe { node.as_ref() }.prev,
+            None => self.list.tail,
+        };
+        prev.map(|node| &mut unsafe { &mut *node.as_ptr() }.key)
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.list.front()
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.list.front_mut()
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.list.back()
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.list.back_mut()
+    }
+
+    // The cursor stays where it is; only its index shifts.
+    pub fn push_front(&mut self, key: T) {
+        self.list.push_front(key);
+        self.index += 1;
+    }
+
+    // Pushed from the ghost, the new element is behind the cursor, which
+    // stays on the ghost.
+    pub fn push_back(&mut self, key: T) {
+        self.list.push_back(key);
+        if self.current.is_none() {
+            self.index += 1;
+        }
+    }
+
+    // Popping the element the cursor is on moves it on to the next one, as
+    // `remove_current` does. The ghost stays the ghost.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.list.head?;
+        if self.current == Some(head) {
+            self.current = unsafe { head.as_ref() }.next;
+        } else {
+            self.index -= 1;
+        }
+        self.list.pop_front()
+    }
+
+    // Popping the element the cursor is on moves it on to the ghost.
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.list.tail?;
+        match self.current {
+            Some(node) if node == tail => self.current = None,
+            Some(_) => {}
+            None => self.index -= 1,
+        }
+        self.list.pop_back()
+    }
+
+    // A read-only cursor at the same position, for as long as it is
+    // borrowed.
+    pub fn as_cursor(&self) -> Cursor<'_, T> {
+        Cursor {
+            index: self.index,
+            current: self.current,
+            list: self.list,
+        }
+    }
+
+    // The cursor stays on its element. On the ghost, the new element goes
+    // in at the front.
+    pub fn insert_after(&mut self, key: T) {
+        match self.current {
+            Some(node) => {
+                unsafe { self.list.push_after(node, key) };
+            }
+            None => {
+                self.list.push_front(key);
+                self.index = self.list.len;
+            }
+        }
+    }
+
+    // The cursor stays on its element. On the ghost, the new element goes
+    // in at the back.
+    pub fn insert_before(&mut self, key: T) {
+        match self.current.and_then(|node| unsafe { node.as_ref() }.prev) {
+            Some(prev) => {
+                unsafe { self.list.push_after(prev, key) };
+            }
+            None if self.current.is_some() => self.list.push_front(key),
+            None => self.list.push_back(key),
+        }
+        self.index += 1;
+    }
+
+    // Moves on to the next element, or to the ghost if the tail was removed.
+    // Nothing is removed from the ghost.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current?;
+        self.current = unsafe { node.as_ref() }.next;
+        Some(unsafe { self.list.unlink(node) })
+    }
+
+    // Moves every element of `other` in right after the cursor, or at the
+    // front from the ghost, without copying them. `other` is left empty.
+    pub fn splice_after(&mut self, other: &mut LinkedList<T>) {
+        let (prev, next) = match self.current {
+            Some(node) => (Some(node), unsafe { node.as_ref() }.next),
+            None => (None, self.list.head),
+        };
+        unsafe { self.list.splice_between(prev, next, other) };
+        if self.current.is_none() {
+            self.index = self.list.len;
+        }
+    }
+
+    // Moves every element of `other` in right before the cursor, or at the
+    // back from the ghost, without copying them. `other` is left empty.
+    pub fn splice_before(&mut self, other: &mut LinkedList<T>) {
+        let (prev, next) = match self.current {
+            Some(node) => (unsafe { node.as_ref() }.prev, Some(node)),
+            None => (self.list.tail, None),
+        };
+        self.index += other.len;
+        unsafe { self.list.splice_between(prev, next, other) };
+    }
+
+    // Cuts the list right after the cursor and returns the part behind it.
+    // From the ghost, that is the whole list.
+    pub fn split_after(&mut self) -> LinkedList<T> {
+        match self.current {
+            Some(node) => unsafe { self.list.split_after(node, self.index) },
+            None => {
+                self.index = 0;
+                self.list.take()
+            }
+        }
+    }
+
+    // Cuts the list right before the cursor and returns the part in front
+    // of it, leaving the cursor at index 0. From the ghost, that is the
+    // whole list.
+    pub fn split_before(&mut self) -> LinkedList<T> {
+        let at = mem::replace(&mut self.index, 0);
+        match self.current {
+            Some(node) => unsafe { self.list.split_before(node, at) },
+            None => self.list.take(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Rng;
+
+    // Moving forward visits every element and then the ghost, and moving on
+    // from the ghost wraps around, both ways.
+    #[test]
+    fn moves_through_the_ghost_both_ways() {
+        let list = LinkedList::from([0, 1, 2]);
+
+        let mut cursor = list.cursor_front();
+        let mut seen = Vec::new();
+        for _ in 0..8 {
+            seen.push(cursor.current().copied());
+            cursor.move_next();
+        }
+        assert_eq!(
+            seen,
+            [
+                Some(0),
+                Some(1),
+                Some(2),
+                None,
+                Some(0),
+                Some(1),
+                Some(2),
+                None
+            ]
+        );
+
+        let mut cursor = list.cursor_back();
+        let mut seen = Vec::new();
+        for _ in 0..5 {
+            seen.push(cursor.current().copied());
+            cursor.move_prev();
+        }
+        assert_eq!(seen, [Some(2), Some(1), Some(0), None, Some(2)]);
+
+        let empty = LinkedList::<i32>::new();
+        let mut cursor = empty.cursor_front();
+        cursor.move_next();
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+    }
+
+    // The cursor next to a `Vec` and an index into it, where `len` stands
+    // for the ghost.
+    struct Model {
+        keys: Vec<i32>,
+        at: usize,
+    }
+
+    impl Model {
+        fn insert_after(&mut self, key: i32) {
+            if self.at == self.keys.len() {
+                self.keys.insert(0, key);
+                self.at = self.keys.len();
+            } else {
+                self.keys.insert(self.at + 1, key);
+            }
+        }
+
+        fn insert_before(&mut self, key: i32) {
+            self.keys.insert(self.at, key);
+            self.at += 1;
+        }
+
+        fn remove_current(&mut self) -> Option<i32> {
+            (self.at < self.keys.len()).then(|| self.keys.remove(self.at))
+        }
+
+        fn move_next(&mut self) {
+            self.at = if self.at == self.keys.len() {
+                0
+            } else {
+                self.at + 1
+            };
+        }
+
+        fn move_prev(&mut self) {
+            self.at = match self.at {
+                0 => self.keys.len(),
+                at if at == self.keys.len() => at.saturating_sub(1),
+                at => at - 1,
+            };
+        }
+
+        fn push_front(&mut self, key: i32) {
+            self.keys.insert(0, key);
+            self.at += 1;
+        }
+
+        fn push_back(&mut self, key: i32) {
+            if self.at == self.keys.len() {
+                self.at += 1;
+            }
+            self.keys.push(key);
+        }
+
+        fn pop_front(&mut self) -> Option<i32> {
+            if self.keys.is_empty() {
+                return None;
+            }
+            self.at = self.at.saturating_sub(1);
+            Some(self.keys.remove(0))
+        }
+
+        fn pop_back(&mut self) -> Option<i32> {
+            if self.at == self.keys.len() {
+                self.at = self.at.saturating_sub(1);
+            }
+            self.keys.pop()
+        }
+
+        fn current(&self) -> Option<&i32> {
+            self.keys.get(self.at)
+        }
+
+        fn index(&self) -> Option<usize> {
+            (self.at < self.keys.len()).then_some(self.at)
+        }
+
+        fn peek_next(&self) -> Option<&i32> {
+            match self.at {
+                at if at == self.keys.len() => self.keys.first(),
+                at => self.keys.get(at + 1),
+            }
+        }
+
+        fn peek_prev(&self) -> Option<&i32> {
+            match self.at {
+                at if at == self.keys.len() => self.keys.last(),
+                0 => None,
+                at => self.keys.get(at - 1),
+            }
+        }
+    }
+
+    #[test]
+    fn edits_match_a_vec_model() {
+        let mut rng = Rng::new(0x51CE);
+        for _ in 0..200 {
+            let mut list = LinkedList::from([0, 1, 2, 3]);
+            let mut model = Model {
+                keys: vec![0, 1, 2, 3],
+                at: 0,
+            };
+
+            let mut cursor = list.cursor_front_mut();
+            for key in 0..40 {
+                match rng.below(9) {
+                    0 => {
+                        cursor.insert_after(key);
+                        model.insert_after(key);
+                    }
+                    1 => {
+                        cursor.insert_before(key);
+                        model.insert_before(key);
+                    }
+                    2 => assert_eq!(cursor.remove_current(), model.remove_current()),
+                    3 => {
+                        cursor.push_front(key);
+                        model.push_front(key);
+                    }
+                    4 => {
+                        cursor.push_back(key);
+                        model.push_back(key);
+                    }
+                    5 => assert_eq!(cursor.pop_front(), model.pop_front()),
+                    6 => assert_eq!(cursor.pop_back(), model.pop_back()),
+                    7 => {
+                        cursor.move_next();
+                        model.move_next();
+                    }
+                    _ => {
+                        cursor.move_prev();
+                        model.move_prev();
+                    }
+                }
+
+                let view = cursor.as_cursor();
+                assert_eq!(view.current(), model.current());
+                assert_eq!(view.index(), model.index());
+                assert_eq!(view.peek_next(), model.peek_next());
+                assert_eq!(view.peek_prev(), model.peek_prev());
+            }
+
+            list.debug_validate();
+            assert!(list.iter().eq(&model.keys));
+        }
+    }
+
+    #[test]
+    fn splices_move_whole_lists_in_place() {
+        let mut list = LinkedList::from([0, 1, 2]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        let mut other = LinkedList::from([10, 11]);
+        cursor.splice_after(&mut other);
+        let mut other = LinkedList::from([20, 21]);
+        cursor.splice_before(&mut other);
+        assert_eq!(cursor.current(), Some(&mut 1));
+
+        // From the ghost, `splice_after` goes to the front and
+        // `splice_before` to the back.
+        cursor.move_prev();
+        cursor.move_prev();
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+        cursor.splice_after(&mut LinkedList::from([30]));
+        cursor.splice_before(&mut LinkedList::from([40]));
+        cursor.splice_after(&mut LinkedList::new());
+
+        assert!(other.is_empty());
+        list.debug_validate();
+        assert!(list.iter().eq(&[30, 0, 20, 21, 1, 10, 11, 2, 40]));
+    }
+
+    #[test]
+    fn splicing_keeps_the_nodes() {
+        let mut list = LinkedList::from([0, 1]);
+        let mut other = LinkedList::from([2, 3]);
+        let nodes: Vec<*const i32> = other.iter().map(|key| key as *const i32).collect();
+
+        list.cursor_back_mut().splice_after(&mut other);
+
+        let moved: Vec<*const i32> = list.iter().skip(2).map(|key| key as *const i32).collect();
+        assert_eq!(moved, nodes);
+    }
+
+    #[test]
+    fn splits_match_split_off_at_every_position() {
+        for len in 0..5 {
+            // `at == len` is the ghost.
+            for at in 0..=len {
+                let keys: Vec<_> = (0..len).collect();
+                let mut list: LinkedList<_> = keys.iter().copied().collect();
+                let mut cursor = list.cursor_front_mut();
+                for _ in 0..at {
+                    cursor.move_next();
+                }
+
+                let back = cursor.split_after();
+                assert_eq!(cursor.as_cursor().current(), keys.get(at));
+                let front = cursor.split_before();
+                cursor.move_next();
+                cursor.move_prev();
+                assert_eq!(cursor.as_cursor().current(), keys.get(at));
+
+                // From the ghost, `split_after` already takes everything.
+                let (expected_front, expected_back) = match at {
+                    at if at == len => (&[][..], &keys[..]),
+                    at => (&keys[..at], &keys[at + 1..]),
+                };
+                for part in [&front, &back, &list] {
+                    part.debug_validate();
+                }
+                assert!(front.iter().eq(expected_front));
+                assert!(back.iter().eq(expected_back));
+                assert!(list.iter().eq(keys.get(at)));
+            }
+        }
+    }
+
+    #[test]
+    fn split_before_peels_off_a_prefix() {
+        let mut list: LinkedList<_> = "key=value".chars().collect();
+        let mut cursor = list.cursor_front_mut();
+        while cursor.current() != Some(&mut '=') {
+            cursor.move_next();
+        }
+
+        let key = cursor.split_before();
+        assert_eq!(cursor.remove_current(), Some('='));
+        assert_eq!(key.into_iter().collect::<String>(), "key");
+        assert_eq!(list.into_iter().collect::<String>(), "value");
+    }
+
+    #[test]
+    fn seeks_and_steps_land_where_an_index_would() {
+        for len in 0..6 {
+            let keys: Vec<_> = (0..len).collect();
+            let list: LinkedList<_> = keys.iter().copied().collect();
+
+            for from in 0..=len {
+                for n in [0, 1, 2, 5, usize::MAX] {
+                    let mut cursor = list.cursor_front();
+                    cursor.seek_to(from);
+                    assert_eq!(cursor.current(), keys.get(from));
+
+                    let mut ahead = cursor;
+                    assert_eq!(ahead.advance_by(n), n.min(len - from));
+                    let at = from.saturating_add(n).min(len);
+                    assert_eq!(ahead.current(), keys.get(at));
+                    assert_eq!(ahead.index(), (at < len).then_some(at));
+
+                    let mut behind = cursor;
+                    assert_eq!(behind.retreat_by(n), n.min(from));
+                    let at = from.saturating_sub(n);
+                    assert_eq!(behind.current(), keys.get(at));
+                    assert_eq!(behind.index(), (at < len).then_some(at));
+                }
+
+                // Seeking from every position to every other one.
+                for to in 0..=len {
+                    let mut list = list.clone();
+                    let mut cursor = list.cursor_back_mut();
+                    cursor.seek_to(from);
+                    cursor.seek_to(to);
+                    assert_eq!(cursor.index(), (to < len).then_some(to));
+                    assert_eq!(cursor.as_cursor().current(), keys.get(to));
+
+                    // The links around where it landed are the right ones.
+                    let prev = match to {
+                        to if to == len => keys.last(),
+                        to => to.checked_sub(1).map(|at| &keys[at]),
+                    };
+                    assert_eq!(cursor.as_cursor().peek_prev(), prev);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bound")]
+    fn seek_past_the_ghost_panics() {
+        let list = LinkedList::from([0, 1]);
+        list.cursor_front().seek_to(3);
+    }
+}
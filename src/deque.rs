@@ -0,0 +1,88 @@
+use alloc::collections::{vec_deque, VecDeque};
+
+use crate::{Iter, LinkedList};
+
+pub trait Deque<T> {
+    type Iter<'a>: Iterator<Item = &'a T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn iter(&self) -> Self::Iter<'_>;
+
+    fn push_front(&mut self, key: T);
+
+    fn push_back(&mut self, key: T);
+
+    fn pop_front(&mut self) -> Option<T>;
+
+    fn pop_back(&mut self) -> Option<T>;
+}
+
+impl<T> Deque<T> for LinkedList<T> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        LinkedList::len(self)
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        LinkedList::iter(self)
+    }
+
+    fn push_front(&mut self, key: T) {
+        LinkedList::push_front(self, key)
+    }
+
+    fn push_back(&mut self, key: T) {
+        LinkedList::push_back(self, key)
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        LinkedList::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        LinkedList::pop_back(self)
+    }
+}
+
+impl<T> Deque<T> for VecDeque<T> {
+    type Iter<'a>
+        = vec_deque::Iter<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    fn iter(&self) -> vec_deque::Iter<'_, T> {
+        VecDeque::iter(self)
+    }
+
+    fn push_front(&mut self, key: T) {
+        VecDeque::push_front(self, key)
+    }
+
+    fn push_back(&mut self, key: T) {
+        VecDeque::push_back(self, key)
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        VecDeque::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        VecDeque::pop_back(self)
+    }
+}
@@ -0,0 +1,224 @@
+use crate::LinkedList;
+use alloc::{vec, vec::Vec};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Edit<'a, T> {
+    Keep(&'a T),
+    Delete(&'a T),
+    Insert(&'a T),
+}
+
+impl<T> LinkedList<T> {
+    // Myers' O((N + M) D) algorithm in its linear-space form: the shortest
+    // edit script turning `self` into `other`.
+    pub fn diff<'a>(&'a self, other: &'a LinkedList<T>) -> Vec<Edit<'a, T>>
+    where
+        T: PartialEq<T>,
+    {
+        traversal_span!("diff", self.len() + other.len());
+
+        let a: Vec<&T> = self.iter().collect();
+        let b: Vec<&T> = other.iter().collect();
+
+        let mut edits = Vec::with_capacity(a.len().max(b.len()));
+        diff_into(&a, &b, &mut edits);
+        edits
+    }
+}
+
+// Trims the common ends, then splits what is left at the middle snake of an
+// optimal path and does each side the same way. Each split halves the edit
+// distance, so the recursion is only O(log D) deep, and nothing but the
+// two frontier vectors of the current snake search is held at a time.
+fn diff_into<'a, T: PartialEq>(mut a: &[&'a T], mut b: &[&'a T], edits: &mut Vec<Edit<'a, T>>) {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    edits.extend(a[..prefix].iter().map(|&key| Edit::Keep(key)));
+    (a, b) = (&a[prefix..], &b[prefix..]);
+
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let kept = &a[a.len() - suffix..];
+    (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+
+    if a.is_empty() {
+        edits.extend(b.iter().map(|&key| Edit::Insert(key)));
+    } else if b.is_empty() {
+        edits.extend(a.iter().map(|&key| Edit::Delete(key)));
+    } else {
+        let (x, y, u, v) = middle_snake(a, b);
+        diff_into(&a[..x], &b[..y], edits);
+        edits.extend(a[x..u].iter().map(|&key| Edit::Keep(key)));
+        diff_into(&a[u..], &b[v..], edits);
+    }
+
+    edits.extend(kept.iter().map(|&key| Edit::Keep(key)));
+}
+
+// Runs the search from both corners at once until the paths meet, and
+// returns the snake where they do as `(x, y)` to `(u, v)`. `forward[k]` is the
+// furthest `x` reached on diagonal `k` from the start, `backward[k]` the
+// furthest distance back from the end on diagonal `k` of the reversed
+// lists; diagonal `k` forward is `delta - k` backward.
+fn middle_snake<T: PartialEq>(a: &[&T], b: &[&T]) -> (usize, usize, usize, usize) {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let delta = n - m;
+    let odd = delta % 2 != 0;
+
+    let max = (n + m + 1) / 2;
+    let offset = max + 1;
+    let index = |k: isize| (k + offset) as usize;
+
+    let mut forward = vec![0; 2 * offset as usize + 1];
+    let mut backward = vec![0; 2 * offset as usize + 1];
+
+    for d in 0..=max {
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && forward[index(k - 1)] < forward[index(k + 1)]) {
+                forward[index(k + 1)]
+            } else {
+                forward[index(k - 1)] + 1
+            };
+            let (start_x, start_y) = (x, x - k);
+            let mut y = start_y;
+
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            forward[index(k)] = x;
+
+            let back = delta - k;
+            if odd && (-(d - 1)..=d - 1).contains(&back) && x + backward[index(back)] >= n {
+                return (start_x as usize, start_y as usize, x as usize, y as usize);
+            }
+        }
+
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && backward[index(k - 1)] < backward[index(k + 1)]) {
+                backward[index(k + 1)]
+            } else {
+                backward[index(k - 1)] + 1
+            };
+            let (start_x, start_y) = (x, x - k);
+            let mut y = start_y;
+
+            while x < n && y < m && a[(n - x - 1) as usize] == b[(m - y - 1) as usize] {
+                x += 1;
+                y += 1;
+            }
+            backward[index(k)] = x;
+
+            let ahead = delta - k;
+            if !odd && (-d..=d).contains(&ahead) && x + forward[index(ahead)] >= n {
+                return (
+                    (n - x) as usize,
+                    (m - y) as usize,
+                    (n - start_x) as usize,
+                    (m - start_y) as usize,
+                );
+            }
+        }
+    }
+
+    unreachable!("the two searches always meet by distance `(n + m + 1) / 2`")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Rng;
+
+    // Replays the script against `from`, checking it consumes `from` and
+    // produces `to`, and returns its number of insertions and deletions.
+    fn replay(from: &[u8], to: &[u8]) -> usize {
+        let a: LinkedList<u8> = from.iter().copied().collect();
+        let b: LinkedList<u8> = to.iter().copied().collect();
+
+        let (mut source, mut target, mut cost) = (Vec::new(), Vec::new(), 0);
+        for edit in a.diff(&b) {
+            match edit {
+                Edit::Keep(key) => {
+                    source.push(*key);
+                    target.push(*key);
+                }
+                Edit::Delete(key) => {
+                    source.push(*key);
+                    cost += 1;
+                }
+                Edit::Insert(key) => {
+                    target.push(*key);
+                    cost += 1;
+                }
+            }
+        }
+
+        assert_eq!(source, from);
+        assert_eq!(target, to);
+        cost
+    }
+
+    fn lcs(a: &[u8], b: &[u8]) -> usize {
+        let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                table[i + 1][j + 1] = match x == y {
+                    true => table[i][j] + 1,
+                    false => table[i][j + 1].max(table[i + 1][j]),
+                };
+            }
+        }
+
+        table[a.len()][b.len()]
+    }
+
+    #[test]
+    fn edge_cases() {
+        assert_eq!(replay(b"", b""), 0);
+        assert_eq!(replay(b"abc", b""), 3);
+        assert_eq!(replay(b"", b"abc"), 3);
+        assert_eq!(replay(b"abc", b"abc"), 0);
+        assert_eq!(replay(b"abcabba", b"cbabac"), 5);
+        assert_eq!(replay(b"xaby", b"zabw"), 4);
+    }
+
+    #[test]
+    fn keeps_refer_to_the_source_list() {
+        let a = LinkedList::from([1, 2, 3]);
+        let b = LinkedList::from([2, 3, 4]);
+        let edits = a.diff(&b);
+
+        assert_eq!(
+            edits,
+            [
+                Edit::Delete(&1),
+                Edit::Keep(&2),
+                Edit::Keep(&3),
+                Edit::Insert(&4)
+            ]
+        );
+        let Edit::Keep(kept) = edits[1] else {
+            unreachable!()
+        };
+        assert!(std::ptr::eq(kept, a.iter().nth(1).unwrap()));
+    }
+
+    #[test]
+    fn random_scripts_rebuild_the_target_and_are_shortest() {
+        let mut rng = Rng::new(0xdead_beef);
+
+        for _ in 0..2000 {
+            let alphabet = 1 + rng.below(4);
+            let a: Vec<u8> = (0..rng.below(16))
+                .map(|_| rng.below(alphabet) as u8)
+                .collect();
+            let b: Vec<u8> = (0..rng.below(16))
+                .map(|_| rng.below(alphabet) as u8)
+                .collect();
+            assert_eq!(replay(&a, &b), a.len() + b.len() - 2 * lcs(&a, &b));
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use alloc::boxed::Box;
+use core::slice;
+
+use crate::LinkedList;
+
+// A read-only list whose elements sit in one contiguous allocation, so
+// scanning it is a slice scan.
+pub struct FrozenList<T> {
+    keys: Box<[T]>,
+}
+
+impl<T> FrozenList<T> {
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.keys.iter()
+    }
+
+    pub fn get(&self, at: usize) -> Option<&T> {
+        self.keys.get(at)
+    }
+
+    pub fn contains(&self, key: &T) -> bool
+    where
+        T: PartialEq<T>,
+    {
+        self.keys.contains(key)
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.keys
+    }
+}
+
+impl<T> LinkedList<T> {
+    pub fn freeze(self) -> FrozenList<T> {
+        FrozenList {
+            keys: self.into_iter().collect(),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a FrozenList<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn freezing_keeps_order_in_one_slice() {
+        let frozen = LinkedList::from([3, 1, 2]).freeze();
+        assert_eq!(frozen.as_slice(), [3, 1, 2]);
+        assert_eq!(frozen.len(), 3);
+        assert_eq!((frozen.get(1), frozen.get(3)), (Some(&1), None));
+        assert!(frozen.contains(&2) && !frozen.contains(&4));
+        assert!((&frozen).into_iter().eq(frozen.iter()));
+
+        let empty = LinkedList::<i32>::new().freeze();
+        assert!(empty.is_empty() && empty.as_slice().is_empty());
+    }
+}
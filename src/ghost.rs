@@ -0,0 +1,346 @@
+use alloc::rc::{Rc, Weak};
+use core::{cell::UnsafeCell, iter::FusedIterator, marker::PhantomData};
+
+use crate::atomic::{AtomicUsize, Ordering};
+
+// GhostCell: a cell is tied to the token of the same brand, and reading or
+// writing it takes a shared or mutable borrow of that token. The brand is an
+// invariant lifetime that only `GhostToken::scope` can introduce, so no two
+// tokens ever share one.
+type Brand<'brand> = PhantomData<fn(&'brand ()) -> &'brand ()>;
+
+pub struct GhostToken<'brand> {
+    brand: Brand<'brand>,
+}
+
+impl GhostToken<'_> {
+    pub fn scope<R, F>(f: F) -> R
+    where
+        F: for<'brand> FnOnce(GhostToken<'brand>) -> R,
+    {
+        f(GhostToken { brand: PhantomData })
+    }
+}
+
+pub struct GhostCell<'brand, T: ?Sized> {
+    brand: Brand<'brand>,
+    value: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for GhostCell<'_, T> {}
+
+unsafe impl<T: ?Sized + Send + Sync> Sync for GhostCell<'_, T> {}
+
+impl<'brand, T> GhostCell<'brand, T> {
+    pub const fn new(value: T) -> GhostCell<'brand, T> {
+        GhostCell {
+            brand: PhantomData,
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<'brand, T: ?Sized> GhostCell<'brand, T> {
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+
+    pub fn borrow<'a>(&'a self, _token: &'a GhostToken<'brand>) -> &'a T {
+        unsafe { &*self.value.get() }
+    }
+
+    pub fn borrow_mut<'a>(&'a self, _token: &'a mut GhostToken<'brand>) -> &'a mut T {
+        unsafe { &mut *self.value.get() }
+    }
+}
+
+// Links forward are strong and links back are weak, so the list owns its
+// nodes without cycles; handles are extra strong references that keep a node
+// readable after it has been removed.
+struct Node<'brand, T> {
+    key: T,
+    owner: usize,
+    prev: Option<Weak<NodeCell<'brand, T>>>,
+    next: Option<Rc<NodeCell<'brand, T>>>,
+}
+
+type NodeCell<'brand, T> = GhostCell<'brand, Node<'brand, T>>;
+
+// Unlinked nodes have owner 0.
+static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+
+pub struct NodeHandle<'brand, T> {
+    node: Rc<NodeCell<'brand, T>>,
+}
+
+pub struct GhostList<'brand, T> {
+    id: usize,
+    head: Option<Rc<NodeCell<'brand, T>>>,
+    tail: Option<Rc<NodeCell<'brand, T>>>,
+    len: usize,
+}
+
+pub struct Iter<'a, 'brand, T> {
+    head: Option<&'a NodeCell<'brand, T>>,
+    len: usize,
+    token: &'a GhostToken<'brand>,
+}
+
+impl<'a, T> Iterator for Iter<'a, '_, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.head?.borrow(self.token);
+
+        self.head = node.next.as_deref();
+        self.len -= 1;
+
+        Some(&node.key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, '_, T> {}
+
+impl<T> FusedIterator for Iter<'_, '_, T> {}
+
+impl<'brand, T> NodeHandle<'brand, T> {
+    pub fn get<'a>(&'a self, token: &'a GhostToken<'brand>) -> &'a T {
+        &self.node.borrow(token).key
+    }
+
+    pub fn get_mut<'a>(&'a self, token: &'a mut GhostToken<'brand>) -> &'a mut T {
+        &mut self.node.borrow_mut(token).key
+    }
+
+    pub fn next(&self, token: &GhostToken<'brand>) -> Option<NodeHandle<'brand, T>> {
+        let node = self.node.borrow(token).next.clone()?;
+        Some(NodeHandle { node })
+    }
+
+    pub fn prev(&self, token: &GhostToken<'brand>) -> Option<NodeHandle<'brand, T>> {
+        let node = self.node.borrow(token).prev.as_ref()?.upgrade()?;
+        Some(NodeHandle { node })
+    }
+
+    pub fn ptr_eq(&self, other: &NodeHandle<'brand, T>) -> bool {
+        Rc::ptr_eq(&self.node, &other.node)
+    }
+
+    // Succeeds once the node is out of every list and this is its last handle.
+    pub fn try_into_inner(self) -> Result<T, NodeHandle<'brand, T>> {
+        match Rc::try_unwrap(self.node) {
+            Ok(node) => Ok(node.into_inner().key),
+            Err(node) => Err(NodeHandle { node }),
+        }
+    }
+}
+
+impl<T> Clone for NodeHandle<'_, T> {
+    fn clone(&self) -> Self {
+        NodeHandle {
+            node: self.node.clone(),
+        }
+    }
+}
+
+impl<'brand, T> GhostList<'brand, T> {
+    pub fn new() -> GhostList<'brand, T> {
+        GhostList {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter<'a>(&'a self, token: &'a GhostToken<'brand>) -> Iter<'a, 'brand, T> {
+        Iter {
+            head: self.head.as_deref(),
+            len: self.len,
+            token,
+        }
+    }
+
+    pub fn front(&self) -> Option<NodeHandle<'brand, T>> {
+        let node = self.head.clone()?;
+        Some(NodeHandle { node })
+    }
+
+    pub fn back(&self) -> Option<NodeHandle<'brand, T>> {
+        let node = self.tail.clone()?;
+        Some(NodeHandle { node })
+    }
+
+    pub fn push_front(&mut self, key: T, token: &mut GhostToken<'brand>) -> NodeHandle<'brand, T> {
+        let node = Rc::new(GhostCell::new(Node {
+            key,
+            owner: self.id,
+            prev: None,
+            next: self.head.clone(),
+        }));
+
+        match self.head.replace(node.clone()) {
+            Some(head) => head.borrow_mut(token).prev = Some(Rc::downgrade(&node)),
+            None => self.tail = Some(node.clone()),
+        }
+        self.len += 1;
+
+        NodeHandle { node }
+    }
+
+    pub fn push_back(&mut self, key: T, token: &mut GhostToken<'brand>) -> NodeHandle<'brand, T> {
+        let node = Rc::new(GhostCell::new(Node {
+            key,
+            owner: self.id,
+            prev: self.tail.as_ref().map(Rc::downgrade),
+            next: None,
+        }));
+
+        match self.tail.replace(node.clone()) {
+            Some(tail) => tail.borrow_mut(token).next = Some(node.clone()),
+            None => self.head = Some(node.clone()),
+        }
+        self.len += 1;
+
+        NodeHandle { node }
+    }
+
+    // Returns false if `handle` is not currently in this list.
+    pub fn remove(
+        &mut self,
+        handle: &NodeHandle<'brand, T>,
+        token: &mut GhostToken<'brand>,
+    ) -> bool {
+        let node = handle.node.borrow_mut(token);
+        if node.owner != self.id {
+            return false;
+        }
+
+        node.owner = 0;
+        let prev = node.prev.take().and_then(|prev| prev.upgrade());
+        let next = node.next.take();
+
+        match &next {
+            Some(next) => next.borrow_mut(token).prev = prev.as_ref().map(Rc::downgrade),
+            None => self.tail = prev.clone(),
+        }
+        match prev {
+            Some(prev) => prev.borrow_mut(token).next = next,
+            None => self.head = next,
+        }
+        self.len -= 1;
+
+        true
+    }
+
+    pub fn pop_front(&mut self, token: &mut GhostToken<'brand>) -> Option<NodeHandle<'brand, T>> {
+        let handle = self.front()?;
+        self.remove(&handle, token);
+        Some(handle)
+    }
+
+    pub fn pop_back(&mut self, token: &mut GhostToken<'brand>) -> Option<NodeHandle<'brand, T>> {
+        let handle = self.back()?;
+        self.remove(&handle, token);
+        Some(handle)
+    }
+}
+
+impl<T> Default for GhostList<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Detaches every node as it goes, so a node a handle still holds does not keep
+// the rest of the chain alive (and free it recursively later), and the handle
+// sees a lone node that is in no list. There is no token here, but a handle
+// can only be holding a borrow of the key, so the links and owner are
+// written through raw field pointers without touching it.
+impl<T> Drop for GhostList<'_, T> {
+    fn drop(&mut self) {
+        self.tail = None;
+
+        let mut node = self.head.take();
+        while let Some(curr) = node {
+            let raw = curr.value.get();
+            unsafe {
+                (*raw).owner = 0;
+                (*raw).prev = None;
+                node = (*raw).next.take();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drop_with_held_head_handle_does_not_recurse() {
+        GhostToken::scope(|mut token| {
+            let mut list = GhostList::new();
+            let first = list.push_back(0, &mut token);
+            for key in 1..1_000_000 {
+                list.push_back(key, &mut token);
+            }
+
+            drop(list);
+            assert!(first.next(&token).is_none());
+            assert_eq!(*first.get(&token), 0);
+            assert_eq!(first.try_into_inner().ok(), Some(0));
+        });
+    }
+
+    #[test]
+    fn handles_outliving_the_list_are_detached() {
+        GhostToken::scope(|mut token| {
+            let mut list = GhostList::new();
+            let handles: Vec<_> = (0..3).map(|key| list.push_back(key, &mut token)).collect();
+            drop(list);
+
+            for handle in &handles {
+                assert!(handle.prev(&token).is_none());
+                assert!(handle.next(&token).is_none());
+            }
+
+            let mut other = GhostList::new();
+            other.push_back(9, &mut token);
+            assert!(!other.remove(&handles[1], &mut token));
+            assert_eq!(other.len(), 1);
+        });
+    }
+
+    #[test]
+    fn remove_and_pop_relink_neighbours() {
+        GhostToken::scope(|mut token| {
+            let mut list = GhostList::new();
+            let handles: Vec<_> = (0..4).map(|key| list.push_back(key, &mut token)).collect();
+
+            assert!(list.remove(&handles[1], &mut token));
+            assert!(!list.remove(&handles[1], &mut token));
+            assert!(handles[0].next(&token).unwrap().ptr_eq(&handles[2]));
+            assert!(handles[2].prev(&token).unwrap().ptr_eq(&handles[0]));
+
+            let back = list.pop_back(&mut token).unwrap();
+            assert!(back.ptr_eq(&handles[3]));
+            assert_eq!(list.iter(&token).copied().collect::<Vec<_>>(), [0, 2]);
+        });
+    }
+}
@@ -0,0 +1,248 @@
+use std::{collections::HashSet, fmt, iter::FusedIterator, marker::PhantomData, ptr::NonNull};
+
+use crate::{LinkedList, Node, VersionMismatch};
+
+// A handle to one node, checked the same way as an `IterToken`: the list only
+// accepts it while it has the id and version it had when the handle was
+// taken, so the node is still linked in. Every structural change, including
+// the ones made through handles, leaves older handles stale; `remove_nodes`
+// and `move_nodes_to_front` take a whole batch at once for that reason.
+pub struct NodeRef<T> {
+    id: usize,
+    version: u64,
+    node: NonNull<Node<T>>,
+}
+
+unsafe impl<T> Send for NodeRef<T> {}
+
+unsafe impl<T> Sync for NodeRef<T> {}
+
+impl<T> Clone for NodeRef<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for NodeRef<T> {}
+
+impl<T> fmt::Debug for NodeRef<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodeRef")
+            .field("id", &self.id)
+            .field("version", &self.version)
+            .field("node", &self.node)
+            .finish()
+    }
+}
+
+pub struct Handles<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    len: usize,
+    id: usize,
+    version: u64,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Handles<'a, T> {
+    type Item = (NodeRef<T>, &'a T);
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.head?;
+
+        self.head = unsafe { node.as_ref() }.next;
+        self.len -= 1;
+
+        let handle = NodeRef {
+            id: self.id,
+            version: self.version,
+            node,
+        };
+        Some((handle, &unsafe { &*node.as_ptr() }.key))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Handles<'_, T> {}
+
+impl<T> FusedIterator for Handles<'_, T> {}
+
+unsafe impl<T: Sync> Send for Handles<'_, T> {}
+
+unsafe impl<T: Sync> Sync for Handles<'_, T> {}
+
+impl<T> LinkedList<T> {
+    pub fn iter_handles(&self) -> Handles<'_, T> {
+        Handles {
+            head: self.head,
+            len: self.len,
+            id: self.id(),
+            version: self.version,
+            marker: PhantomData,
+        }
+    }
+
+    fn check_handle(&self, handle: &NodeRef<T>) -> Result<(), VersionMismatch> {
+        if handle.id != self.id() || handle.version != self.version {
+            return Err(VersionMismatch {
+                expected: handle.version,
+                found: self.version,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn get_node(&self, handle: NodeRef<T>) -> Result<&T, VersionMismatch> {
+        self.check_handle(&handle)?;
+        Ok(&unsafe { &*handle.node.as_ptr() }.key)
+    }
+
+    pub fn get_node_mut(&mut self, handle: NodeRef<T>) -> Result<&mut T, VersionMismatch> {
+        self.check_handle(&handle)?;
+        Ok(&mut unsafe { &mut *handle.node.as_ptr() }.key)
+    }
+
+    pub fn remove_node(&mut self, handle: NodeRef<T>) -> Result<T, VersionMismatch> {
+        self.check_handle(&handle)?;
+        Ok(unsafe { self.unlink(handle.node) })
+    }
+
+    // Checks every handle, then drops repeats, keeping first-given order.
+    fn checked_nodes<I>(&self, handles: I) -> Result<Vec<NonNull<Node<T>>>, VersionMismatch>
+    where
+        I: IntoIterator<Item = NodeRef<T>>,
+    {
+        let mut seen = HashSet::new();
+        let mut nodes = Vec::new();
+        for handle in handles {
+            self.check_handle(&handle)?;
+            if seen.insert(handle.node) {
+                nodes.push(handle.node);
+            }
+        }
+
+        Ok(nodes)
+    }
+
+    // Checks every handle before removing any, so on error the list is left
+    // as it was. Repeated handles are removed once; the elements come back in
+    // the order their handles were first given.
+    pub fn remove_nodes<I>(&mut self, handles: I) -> Result<Vec<T>, VersionMismatch>
+    where
+        I: IntoIterator<Item = NodeRef<T>>,
+    {
+        Ok(self
+            .checked_nodes(handles)?
+            .into_iter()
+            .map(|node| unsafe { self.unlink(node) })
+            .collect())
+    }
+
+    pub fn move_node_to_front(&mut self, handle: NodeRef<T>) -> Result<(), VersionMismatch> {
+        self.check_handle(&handle)?;
+        unsafe { self.move_to_front(handle.node) };
+        Ok(())
+    }
+
+    // Like `remove_nodes`, all or nothing. The nodes end up at the front in
+    // the order their handles were first given.
+    pub fn move_nodes_to_front<I>(&mut self, handles: I) -> Result<(), VersionMismatch>
+    where
+        I: IntoIterator<Item = NodeRef<T>>,
+    {
+        for node in self.checked_nodes(handles)?.into_iter().rev() {
+            unsafe { self.move_to_front(node) };
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn handles_of(list: &LinkedList<i32>) -> Vec<NodeRef<i32>> {
+        list.iter_handles().map(|(handle, _)| handle).collect()
+    }
+
+    #[test]
+    fn handles_read_and_write_their_node() {
+        let mut list = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        let handles = handles_of(&list);
+        assert_eq!(list.iter_handles().len(), 6);
+
+        *list.get_node_mut(handles[2]).unwrap() += 10;
+        assert_eq!(list.get_node(handles[2]), Ok(&12));
+        assert_eq!(list.get_node(handles[5]), Ok(&5));
+    }
+
+    #[test]
+    fn every_structural_change_makes_handles_stale() {
+        let mut list = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        let handles = handles_of(&list);
+
+        assert_eq!(list.remove_node(handles[1]), Ok(1));
+        assert!(list.get_node(handles[0]).is_err());
+        assert!(list.move_node_to_front(handles[2]).is_err());
+        assert!(list.eq_by([0, 2, 3, 4, 5], |a, b| *a == b));
+    }
+
+    #[test]
+    fn take_moves_handle_validity_with_the_nodes() {
+        let mut list = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        let handles = handles_of(&list);
+
+        let taken = list.take();
+        assert!(list.get_node(handles[0]).is_err());
+        assert_eq!(taken.get_node(handles[0]), Ok(&0));
+    }
+
+    #[test]
+    fn swap_with_and_split_off_make_handles_stale() {
+        let mut first = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        let mut second = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        let first_handles = handles_of(&first);
+        let second_handles = handles_of(&second);
+
+        first.swap_with(&mut second);
+        for list in [&first, &second] {
+            assert!(list.get_node(first_handles[0]).is_err());
+            assert!(list.get_node(second_handles[0]).is_err());
+        }
+
+        let handles = handles_of(&first);
+        let back = first.split_off(3);
+        assert!(first.get_node(handles[0]).is_err());
+        assert!(back.get_node(handles[3]).is_err());
+    }
+
+    #[test]
+    fn remove_nodes_is_all_or_nothing() {
+        let mut list = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        let handles = handles_of(&list);
+        let other = handles_of(&LinkedList::from([0]))[0];
+
+        assert!(list.remove_nodes([handles[4], other]).is_err());
+        assert_eq!(list.len(), 6);
+
+        let removed = list.remove_nodes([handles[4], handles[0], handles[4]]);
+        assert_eq!(removed, Ok(vec![4, 0]));
+        assert!(list.eq_by([1, 2, 3, 5], |a, b| *a == b));
+        list.debug_validate();
+    }
+
+    #[test]
+    fn move_nodes_to_front_keeps_the_given_order() {
+        let mut list = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        let handles = handles_of(&list);
+
+        list.move_nodes_to_front([handles[3], handles[5], handles[0], handles[3]])
+            .unwrap();
+        assert!(list.eq_by([3, 5, 0, 1, 2, 4], |a, b| *a == b));
+        assert!(list.move_nodes_to_front([handles[1]]).is_err());
+        list.debug_validate();
+    }
+}
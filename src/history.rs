@@ -0,0 +1,129 @@
+use crate::LinkedList;
+
+// `past` runs from the oldest retained state to the current one at its back;
+// `future` holds undone states, the next one to redo at its front. Pushing a
+// new state drops the future, and going over `capacity` evicts the oldest.
+pub struct History<T> {
+    past: LinkedList<T>,
+    future: LinkedList<T>,
+    capacity: usize,
+}
+
+impl<T> History<T> {
+    pub const fn new(capacity: usize) -> History<T> {
+        assert!(capacity != 0, "Capacity must be non-zero");
+
+        History {
+            past: LinkedList::new(),
+            future: LinkedList::new(),
+            capacity,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.past.len() + self.future.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.past.is_empty()
+    }
+
+    pub fn current(&self) -> Option<&T> {
+        self.past.back()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.past.len() > 1
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.future.is_empty()
+    }
+
+    // Returns the state evicted to stay within capacity, if any.
+    pub fn push_state(&mut self, state: T) -> Option<T> {
+        self.future.clear();
+        self.past.push_back(state);
+
+        if self.past.len() > self.capacity {
+            return self.past.pop_front();
+        }
+
+        None
+    }
+
+    pub fn undo(&mut self) -> Option<&T> {
+        if !self.can_undo() {
+            return None;
+        }
+
+        let state = self.past.pop_back()?;
+        self.future.push_front(state);
+        self.past.back()
+    }
+
+    pub fn redo(&mut self) -> Option<&T> {
+        let state = self.future.pop_front()?;
+        self.past.push_back(state);
+        self.past.back()
+    }
+
+    pub fn clear(&mut self) {
+        self.past.clear();
+        self.future.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_and_redo_walk_the_states() {
+        let mut history = History::new(4);
+        assert_eq!(history.current(), None);
+        assert_eq!(history.undo(), None);
+        for state in 0..3 {
+            assert_eq!(history.push_state(state), None);
+        }
+
+        assert_eq!(history.undo(), Some(&1));
+        assert_eq!(history.undo(), Some(&0));
+        // The first state cannot be undone.
+        assert_eq!(history.undo(), None);
+        assert!(!history.can_undo() && history.can_redo());
+        assert_eq!(history.len(), 3);
+
+        assert_eq!(history.redo(), Some(&1));
+        assert_eq!(history.current(), Some(&1));
+
+        // A new state drops what was left to redo.
+        history.push_state(5);
+        assert!(!history.can_redo());
+        assert_eq!(history.redo(), None);
+        assert_eq!(history.len(), 3);
+    }
+
+    #[test]
+    fn going_over_capacity_evicts_the_oldest() {
+        let mut history = History::new(2);
+        history.push_state('a');
+        history.push_state('b');
+        assert_eq!(history.push_state('c'), Some('a'));
+        assert_eq!(history.undo(), Some(&'b'));
+        assert_eq!(history.undo(), None);
+
+        history.clear();
+        assert!(history.is_empty() && history.capacity() == 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Capacity must be non-zero")]
+    fn zero_capacity_panics() {
+        History::<i32>::new(0);
+    }
+}
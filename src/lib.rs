@@ -1,296 +1,2526 @@
-use std::{marker::PhantomData, ptr::NonNull};
-
-struct Node<T> {
-    key: T,
-    prev: Option<NonNull<Node<T>>>,
-    next: Option<NonNull<Node<T>>>,
-}
-
-impl<T> Node<T> {
-    fn new(key: T) -> Node<T> {
-        Node {
-            key,
-            prev: None,
-            next: None,
-        }
-    }
-}
-
-pub struct LinkedList<T> {
-    head: Option<NonNull<Node<T>>>,
-    tail: Option<NonNull<Node<T>>>,
-    len: usize,
-}
-
-pub struct Iter<'a, T> {
-    head: Option<NonNull<Node<T>>>,
-    _tail: Option<NonNull<Node<T>>>,
-    len: usize,
-    marker: PhantomData<&'a Node<T>>,
-}
-
-impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = &'a T;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.len == 0 {
-            return None;
-        }
-
-        let node = unsafe { self.head.unwrap_unchecked() };
-
-        self.head = unsafe { node.as_ref() }.next;
-        self.len -= 1;
-
-        Some(&unsafe { node.as_ref() }.key)
-    }
-}
-
-pub struct IterMut<'a, T> {
-    head: Option<NonNull<Node<T>>>,
-    _tail: Option<NonNull<Node<T>>>,
-    len: usize,
-    marker: PhantomData<&'a mut Node<T>>,
-}
-
-impl<'a, T> Iterator for IterMut<'a, T> {
-    type Item = &'a mut T;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.len == 0 {
-            return None;
-        }
-
-        let mut node = unsafe { self.head.unwrap_unchecked() };
-
-        self.head = unsafe { node.as_ref() }.next;
-        self.len -= 1;
-
-        Some(&mut unsafe { node.as_mut() }.key)
-    }
-}
-
-impl<T> LinkedList<T> {
-    pub const fn new() -> LinkedList<T> {
-        LinkedList {
-            head: None,
-            tail: None,
-            len: 0,
-        }
-    }
-
-    pub fn len(&self) -> usize {
-        self.len
-    }
-
-    pub fn is_empty(&self) -> bool {
-        self.len == 0
-    }
-
-    pub fn iter(&self) -> Iter<'_, T> {
-        Iter {
-            head: self.head,
-            _tail: self.tail,
-            len: self.len,
-            marker: PhantomData,
-        }
-    }
-
-    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
-        IterMut {
-            head: self.head,
-            _tail: self.tail,
-            len: self.len,
-            marker: PhantomData,
-        }
-    }
-
-    pub fn push_front(&mut self, key: T) {
-        if self.head.is_none() {
-            let node = NonNull::new(Box::into_raw(Box::new(Node::new(key))));
-            self.head = node;
-            self.tail = node;
-            self.len = 1;
-            return;
-        }
-
-        let node = NonNull::new(Box::into_raw(Box::new(Node {
-            key,
-            prev: None,
-            next: self.head,
-        })));
-        unsafe { self.head.unwrap_unchecked().as_mut() }.prev = node;
-        self.head = node;
-        self.len += 1;
-    }
-
-    pub fn push_back(&mut self, key: T) {
-        if self.tail.is_none() {
-            let node = NonNull::new(Box::into_raw(Box::new(Node::new(key))));
-            self.head = node;
-            self.tail = node;
-            self.len = 1;
-            return;
-        }
-
-        let node = NonNull::new(Box::into_raw(Box::new(Node {
-            key,
-            prev: self.tail,
-            next: None,
-        })));
-        unsafe { self.tail.unwrap_unchecked().as_mut() }.next = node;
-        self.tail = node;
-        self.len += 1;
-    }
-
-    pub fn push_at(&mut self, at: usize, key: T) {
-        assert!(
-            at <= self.len,
-            "Index out of bound: len is `{}` but index is `{}`",
-            self.len,
-            at
-        );
-
-        if at == 0 {
-            return self.push_front(key);
-        }
-
-        if at == self.len() {
-            return self.push_back(key);
-        }
-
-        let mut prev_node = unsafe { self.head.unwrap_unchecked() };
-        let mut post_node = unsafe { prev_node.as_ref().next.unwrap_unchecked() };
-
-        for _ in 1..at {
-            prev_node = post_node;
-            post_node = unsafe { post_node.as_ref().next.unwrap_unchecked() };
-        }
-
-        let node = NonNull::new(Box::into_raw(Box::new(Node {
-            key,
-            prev: Some(prev_node),
-            next: Some(post_node),
-        })));
-
-        unsafe { prev_node.as_mut() }.next = node;
-        unsafe { post_node.as_mut() }.prev = node;
-
-        self.len += 1;
-    }
-
-    pub fn pop_back(&mut self) -> Option<T> {
-        if self.tail.is_none() {
-            return None;
-        }
-
-        let tail = unsafe { self.tail.unwrap_unchecked() };
-
-        let node = unsafe { Box::from_raw(tail.as_ptr()) };
-
-        if self.len == 1 {
-            self.head = None;
-            self.tail = None;
-            self.len = 0;
-        } else {
-            self.tail = unsafe { tail.as_ref() }.prev;
-            self.tail = self.tail.map(|mut tail| {
-                unsafe { tail.as_mut() }.next = None;
-                tail
-            });
-            self.len -= 1;
-        }
-
-        Some(node.key)
-    }
-
-    pub fn pop_front(&mut self) -> Option<T> {
-        if self.head.is_none() {
-            return None;
-        }
-
-        let head = unsafe { self.head.unwrap_unchecked() };
-
-        let node = unsafe { Box::from_raw(head.as_ptr()) };
-
-        if self.len == 1 {
-            self.head = None;
-            self.tail = None;
-            self.len = 0;
-        } else {
-            self.head = unsafe { head.as_ref() }.next;
-            self.len -= 1;
-        }
-
-        Some(node.key)
-    }
-
-    pub fn pop_at(&mut self, at: usize) -> Option<T> {
-        assert!(
-            at < self.len,
-            "Index out of bound: len is `{}` but index is `{}`",
-            self.len,
-            at
-        );
-
-        if at == 0 {
-            return self.pop_front();
-        }
-
-        if at == self.len - 1 {
-            return self.pop_back();
-        }
-
-        let mut prev_node = unsafe { self.head.unwrap_unchecked() };
-        let mut post_node = unsafe {
-            prev_node
-                .as_ref()
-                .next
-                .unwrap_unchecked()
-                .as_ref()
-                .next
-                .unwrap_unchecked()
-        };
-
-        for _ in 1..at {
-            prev_node = unsafe { prev_node.as_ref().next.unwrap_unchecked() };
-            post_node = unsafe { post_node.as_ref().next.unwrap_unchecked() };
-        }
-
-        let node = unsafe { prev_node.as_ref().next.unwrap_unchecked() };
-        let node = unsafe { Box::from_raw(node.as_ptr()) };
-
-        unsafe { prev_node.as_mut() }.next = Some(post_node);
-        unsafe { post_node.as_mut() }.prev = Some(prev_node);
-
-        self.len -= 1;
-
-        Some(node.key)
-    }
-
-    pub fn contains(&self, key: &T) -> bool
-    where
-        T: PartialEq<T>,
-    {
-        for x in self.iter() {
-            if *x == *key {
-                return true;
-            }
-        }
-
-        false
-    }
-}
-
-impl<T> Drop for LinkedList<T> {
-    fn drop(&mut self) {
-        if self.head.is_none() {
-            return;
-        }
-
-        while !self.is_empty() {
-            let to_free = unsafe { self.head.unwrap_unchecked() };
-            self.head = unsafe { to_free.as_ref() }.next;
-            self.len -= 1;
-            let _ = unsafe { Box::from_raw(to_free.as_ptr()) };
-        }
-    }
-}
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
+use core::{
+    alloc::Layout,
+    array,
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::{self, FusedIterator},
+    marker::PhantomData,
+    mem,
+    ptr::{self, NonNull},
+};
+
+use crate::atomic::AtomicUsize;
+
+#[macro_use]
+mod trace;
+
+mod adapters;
+mod assoc;
+#[cfg(feature = "async")]
+pub mod async_queue;
+mod atomic;
+#[cfg(feature = "std")]
+mod background;
+pub mod bounded;
+mod cache;
+mod checked;
+#[cfg(feature = "std")]
+mod codec;
+pub mod concurrent;
+mod cursor;
+mod deque;
+mod diff;
+mod frozen;
+pub mod ghost;
+#[cfg(feature = "std")]
+mod handles;
+mod history;
+pub mod observe;
+pub mod persistent;
+pub mod pool;
+mod priority;
+#[cfg(feature = "std")]
+pub mod rcu;
+pub mod rope;
+mod self_organizing;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "serde")]
+pub mod serde_map;
+#[cfg(feature = "std")]
+mod set;
+#[cfg(feature = "std")]
+pub mod shared;
+#[cfg(any(feature = "deepsize", feature = "malloc_size_of"))]
+mod size_of;
+#[cfg(feature = "std")]
+pub mod stamped;
+#[cfg(feature = "stats")]
+mod stats;
+mod sublist;
+pub mod tagged;
+#[cfg(test)]
+mod testing;
+#[cfg(feature = "std")]
+mod timer;
+pub mod undo;
+mod version;
+mod visit;
+
+pub use adapters::{Queue, Stack};
+pub use assoc::{Entry, OccupiedEntry, VacantEntry};
+#[cfg(feature = "async")]
+pub use async_queue::AsyncQueue;
+pub use bounded::{BoundedList, EvictionPolicy};
+#[cfg(feature = "std")]
+pub use concurrent::ConcurrentList;
+pub use cursor::{Cursor, CursorMut};
+pub use deque::Deque;
+pub use diff::Edit;
+pub use frozen::FrozenList;
+#[cfg(feature = "std")]
+pub use handles::{Handles, NodeRef};
+pub use history::History;
+pub use observe::{Change, ObservedList, Observer};
+pub use persistent::PersistentList;
+pub use pool::{Pool, Slot};
+pub use priority::PriorityList;
+#[cfg(feature = "std")]
+pub use rcu::RcuList;
+pub use rope::Rope;
+pub use self_organizing::SelfOrganizingList;
+#[cfg(feature = "std")]
+pub use shared::SharedLinkedList;
+#[cfg(feature = "std")]
+pub use stamped::{StaleHandle, StampedList, StampedRef};
+#[cfg(feature = "stats")]
+pub use stats::Stats;
+pub use sublist::{SubList, SubListMut};
+pub use tagged::TaggedList;
+#[cfg(feature = "std")]
+pub use timer::{TimerHandle, TimerWheel};
+pub use undo::UndoList;
+pub use version::{IterToken, Resumable, VersionMismatch};
+pub use visit::VisitContext;
+
+struct Node<T> {
+    key: T,
+    prev: Option<NonNull<Node<T>>>,
+    next: Option<NonNull<Node<T>>>,
+}
+
+// Freed nodes are filled with this byte under the `poison` feature.
+const POISON: u8 = 0xA5;
+
+impl<T> Node<T> {
+    fn new(key: T) -> Node<T> {
+        Node {
+            key,
+            prev: None,
+            next: None,
+        }
+    }
+
+    fn alloc(node: Node<T>) -> NonNull<Node<T>> {
+        Node::alloc_reused(node).0
+    }
+
+    // Also says whether the node cache supplied the memory.
+    fn alloc_reused(node: Node<T>) -> (NonNull<Node<T>>, bool) {
+        let (ptr, reused) = cache::alloc(Layout::new::<Node<T>>());
+        let ptr = ptr.cast::<Node<T>>();
+        unsafe { ptr::write(ptr.as_ptr(), node) };
+        (ptr, reused)
+    }
+
+    // Deallocates an unlinked node and hands back its key.
+    unsafe fn free(node: NonNull<Node<T>>) -> T {
+        let key = ptr::read(&node.as_ref().key);
+
+        #[cfg(feature = "poison")]
+        ptr::write_bytes(
+            node.as_ptr().cast::<u8>(),
+            POISON,
+            mem::size_of::<Node<T>>(),
+        );
+
+        cache::dealloc(node.cast(), Layout::new::<Node<T>>());
+        key
+    }
+}
+
+pub struct LinkedList<T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    version: u64,
+    id: AtomicUsize,
+    #[cfg(feature = "stats")]
+    stats: Stats,
+}
+
+// Bumps one of the counters in `LinkedList::stats`; compiles to nothing
+// without the `stats` feature.
+macro_rules! record {
+    ($list:expr, $counter:ident) => {
+        record!($list, $counter, 1)
+    };
+    ($list:expr, $counter:ident, $n:expr) => {
+        #[cfg(feature = "stats")]
+        {
+            $list.stats.$counter += $n as u64;
+        }
+    };
+}
+
+unsafe impl<T: Send> Send for LinkedList<T> {}
+
+unsafe impl<T: Sync> Sync for LinkedList<T> {}
+
+pub struct Iter<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let node = unsafe { self.head.unwrap_unchecked() };
+
+        self.head = unsafe { node.as_ref() }.next;
+        self.len -= 1;
+
+        Some(&unsafe { node.as_ref() }.key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            self.len = 0;
+            return None;
+        }
+
+        for _ in 0..n {
+            self.head = unsafe { self.head.unwrap_unchecked().as_ref() }.next;
+        }
+        self.len -= n;
+
+        self.next()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let node = unsafe { self.tail.unwrap_unchecked() };
+
+        self.tail = unsafe { node.as_ref() }.prev;
+        self.len -= 1;
+
+        Some(&unsafe { node.as_ref() }.key)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            self.len = 0;
+            return None;
+        }
+
+        for _ in 0..n {
+            self.tail = unsafe { self.tail.unwrap_unchecked().as_ref() }.prev;
+        }
+        self.len -= n;
+
+        self.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+// Derives would put the bounds on `T`.
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter { ..*self }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<T> Default for Iter<'_, T> {
+    fn default() -> Self {
+        Iter {
+            head: None,
+            tail: None,
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+}
+
+pub struct IterMut<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let mut node = unsafe { self.head.unwrap_unchecked() };
+
+        self.head = unsafe { node.as_ref() }.next;
+        self.len -= 1;
+
+        Some(&mut unsafe { node.as_mut() }.key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            self.len = 0;
+            return None;
+        }
+
+        for _ in 0..n {
+            self.head = unsafe { self.head.unwrap_unchecked().as_ref() }.next;
+        }
+        self.len -= n;
+
+        self.next()
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let mut node = unsafe { self.tail.unwrap_unchecked() };
+
+        self.tail = unsafe { node.as_ref() }.prev;
+        self.len -= 1;
+
+        Some(&mut unsafe { node.as_mut() }.key)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            self.len = 0;
+            return None;
+        }
+
+        for _ in 0..n {
+            self.tail = unsafe { self.tail.unwrap_unchecked().as_ref() }.prev;
+        }
+        self.len -= n;
+
+        self.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+impl<T: fmt::Debug> fmt::Debug for IterMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let iter = Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        };
+        f.debug_list().entries(iter).finish()
+    }
+}
+
+impl<T> Default for IterMut<'_, T> {
+    fn default() -> Self {
+        IterMut {
+            head: None,
+            tail: None,
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+}
+
+pub struct IntoIter<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+
+    fn count(self) -> usize {
+        self.list.len
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T: Clone> Clone for IntoIter<T> {
+    fn clone(&self) -> Self {
+        IntoIter {
+            list: self.list.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.list.iter()).finish()
+    }
+}
+
+impl<T> Default for IntoIter<T> {
+    fn default() -> Self {
+        IntoIter {
+            list: LinkedList::new(),
+        }
+    }
+}
+
+pub struct Pairs<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Pairs<'a, T> {
+    type Item = (&'a T, &'a T);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let node = unsafe { self.head.unwrap_unchecked() };
+        let next = unsafe { node.as_ref().next.unwrap_unchecked() };
+
+        self.head = Some(next);
+        self.len -= 1;
+
+        Some((&unsafe { node.as_ref() }.key, &unsafe { next.as_ref() }.key))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Pairs<'_, T> {}
+
+impl<T> FusedIterator for Pairs<'_, T> {}
+
+unsafe impl<T: Sync> Send for Pairs<'_, T> {}
+
+unsafe impl<T: Sync> Sync for Pairs<'_, T> {}
+
+pub struct Cycle<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    node: Option<NonNull<Node<T>>>,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Cycle<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.node.or(self.head)?;
+
+        self.node = unsafe { node.as_ref() }.next;
+
+        Some(&unsafe { node.as_ref() }.key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.head {
+            Some(_) => (usize::MAX, None),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<T> FusedIterator for Cycle<'_, T> {}
+
+unsafe impl<T: Sync> Send for Cycle<'_, T> {}
+
+unsafe impl<T: Sync> Sync for Cycle<'_, T> {}
+
+pub struct Chunks<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    len: usize,
+    size: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = Iter<'a, T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let len = self.size.min(self.len);
+        let head = self.head;
+
+        let mut tail = unsafe { head.unwrap_unchecked() };
+        for _ in 1..len {
+            tail = unsafe { tail.as_ref().next.unwrap_unchecked() };
+        }
+
+        self.head = unsafe { tail.as_ref() }.next;
+        self.len -= len;
+
+        Some(Iter {
+            head,
+            tail: Some(tail),
+            len,
+            marker: PhantomData,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len.div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for Chunks<'_, T> {}
+
+impl<T> FusedIterator for Chunks<'_, T> {}
+
+unsafe impl<T: Sync> Send for Chunks<'_, T> {}
+
+unsafe impl<T: Sync> Sync for Chunks<'_, T> {}
+
+pub struct ChunkBy<'a, T, P> {
+    head: Option<NonNull<Node<T>>>,
+    len: usize,
+    predicate: P,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T, P> Iterator for ChunkBy<'a, T, P>
+where
+    P: FnMut(&T, &T) -> bool,
+{
+    type Item = Iter<'a, T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let head = self.head;
+
+        let mut tail = unsafe { head.unwrap_unchecked() };
+        let mut len = 1;
+        while len < self.len {
+            let next = unsafe { tail.as_ref().next.unwrap_unchecked() };
+            if !(self.predicate)(&unsafe { tail.as_ref() }.key, &unsafe { next.as_ref() }.key) {
+                break;
+            }
+            tail = next;
+            len += 1;
+        }
+
+        self.head = unsafe { tail.as_ref() }.next;
+        self.len -= len;
+
+        Some(Iter {
+            head,
+            tail: Some(tail),
+            len,
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<T, P> FusedIterator for ChunkBy<'_, T, P> where P: FnMut(&T, &T) -> bool {}
+
+unsafe impl<T: Sync, P: Send> Send for ChunkBy<'_, T, P> {}
+
+unsafe impl<T: Sync, P: Sync> Sync for ChunkBy<'_, T, P> {}
+
+pub struct Split<'a, T, P> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    predicate: P,
+    finished: bool,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T, P> Split<'a, T, P> {
+    fn finish(&mut self) -> Iter<'a, T> {
+        self.finished = true;
+
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, P> Iterator for Split<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = Iter<'a, T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let head = self.head;
+
+        let mut tail = None;
+        let mut node = self.head;
+        for len in 0..self.len {
+            let separator = unsafe { node.unwrap_unchecked() };
+
+            if (self.predicate)(&unsafe { separator.as_ref() }.key) {
+                self.head = unsafe { separator.as_ref() }.next;
+                self.len -= len + 1;
+
+                return Some(Iter {
+                    head,
+                    tail,
+                    len,
+                    marker: PhantomData,
+                });
+            }
+
+            tail = node;
+            node = unsafe { separator.as_ref() }.next;
+        }
+
+        Some(self.finish())
+    }
+}
+
+impl<T, P> FusedIterator for Split<'_, T, P> where P: FnMut(&T) -> bool {}
+
+unsafe impl<T: Sync, P: Send> Send for Split<'_, T, P> {}
+
+unsafe impl<T: Sync, P: Sync> Sync for Split<'_, T, P> {}
+
+pub struct SplitN<'a, T, P> {
+    inner: Split<'a, T, P>,
+    count: usize,
+}
+
+impl<'a, T, P> Iterator for SplitN<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = Iter<'a, T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                if self.inner.finished {
+                    None
+                } else {
+                    Some(self.inner.finish())
+                }
+            }
+            _ => {
+                self.count -= 1;
+                self.inner.next()
+            }
+        }
+    }
+}
+
+pub struct IntoSplit<T, P> {
+    list: LinkedList<T>,
+    predicate: P,
+    finished: bool,
+}
+
+impl<T, P> IntoSplit<T, P> {
+    fn finish(&mut self) -> LinkedList<T> {
+        self.finished = true;
+        mem::take(&mut self.list)
+    }
+}
+
+impl<T, P> Iterator for IntoSplit<T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = LinkedList<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let mut node = self.list.head;
+        for at in 0..self.list.len {
+            let separator = unsafe { node.unwrap_unchecked() };
+
+            if (self.predicate)(&unsafe { separator.as_ref() }.key) {
+                let segment = unsafe { self.list.split_before(separator, at) };
+                self.list.pop_front();
+
+                return Some(segment);
+            }
+
+            node = unsafe { separator.as_ref() }.next;
+        }
+
+        Some(self.finish())
+    }
+}
+
+pub struct IntoSplitN<T, P> {
+    inner: IntoSplit<T, P>,
+    count: usize,
+}
+
+impl<T, P> Iterator for IntoSplitN<T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = LinkedList<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                if self.inner.finished {
+                    None
+                } else {
+                    Some(self.inner.finish())
+                }
+            }
+            _ => {
+                self.count -= 1;
+                self.inner.next()
+            }
+        }
+    }
+}
+
+impl<T> LinkedList<T> {
+    pub const fn new() -> LinkedList<T> {
+        LinkedList {
+            head: None,
+            tail: None,
+            len: 0,
+            version: 0,
+            id: AtomicUsize::new(0),
+            #[cfg(feature = "stats")]
+            stats: Stats::new(),
+        }
+    }
+
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    #[cfg(feature = "stats")]
+    pub fn reset_stats(&mut self) {
+        self.stats = Stats::new();
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        drop(self.take());
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|head| &unsafe { head.as_ref() }.key)
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.map(|mut head| &mut unsafe { head.as_mut() }.key)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|tail| &unsafe { tail.as_ref() }.key)
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail.map(|mut tail| &mut unsafe { tail.as_mut() }.key)
+    }
+
+    // Panics if the links disagree with each other or with `len`, or if one
+    // of them holds the `poison` pattern left behind by a freed node.
+    pub fn debug_validate(&self) {
+        let poisoned = usize::from_ne_bytes([POISON; mem::size_of::<usize>()]);
+        let check = |link: Option<NonNull<Node<T>>>, at: usize| {
+            assert!(
+                link.is_none_or(|node| node.as_ptr() as usize != poisoned),
+                "Link at index `{}` points into a freed node",
+                at
+            );
+        };
+
+        check(self.head, 0);
+        check(self.tail, self.len.saturating_sub(1));
+
+        let mut prev = None;
+        let mut node = self.head;
+        for at in 0..self.len {
+            let Some(curr) = node else {
+                panic!("List ends at index `{}` but len is `{}`", at, self.len);
+            };
+
+            let curr = unsafe { curr.as_ref() };
+            check(curr.prev, at);
+            check(curr.next, at);
+            assert!(
+                curr.prev == prev,
+                "Node at index `{}` has a wrong prev link",
+                at
+            );
+
+            prev = node;
+            node = curr.next;
+        }
+
+        assert!(node.is_none(), "List continues past len `{}`", self.len);
+        assert!(prev == self.tail, "Tail is not the last node");
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn iter_pairs(&self) -> Pairs<'_, T> {
+        Pairs {
+            head: self.head,
+            len: self.len.saturating_sub(1),
+            marker: PhantomData,
+        }
+    }
+
+    pub fn cycle_iter(&self) -> Cycle<'_, T> {
+        Cycle {
+            head: self.head,
+            node: self.head,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn chunks(&self, size: usize) -> Chunks<'_, T> {
+        assert!(size != 0, "Chunk size must be non-zero");
+
+        Chunks {
+            head: self.head,
+            len: self.len,
+            size,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn chunk_by<P>(&self, predicate: P) -> ChunkBy<'_, T, P>
+    where
+        P: FnMut(&T, &T) -> bool,
+    {
+        ChunkBy {
+            head: self.head,
+            len: self.len,
+            predicate,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn split<P>(&self, predicate: P) -> Split<'_, T, P>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        Split {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            predicate,
+            finished: false,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn splitn<P>(&self, n: usize, predicate: P) -> SplitN<'_, T, P>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        SplitN {
+            inner: self.split(predicate),
+            count: n,
+        }
+    }
+
+    pub fn into_split<P>(self, predicate: P) -> IntoSplit<T, P>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        IntoSplit {
+            list: self,
+            predicate,
+            finished: false,
+        }
+    }
+
+    pub fn into_splitn<P>(self, n: usize, predicate: P) -> IntoSplitN<T, P>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        IntoSplitN {
+            inner: self.into_split(predicate),
+            count: n,
+        }
+    }
+
+    // Walks from whichever end is closer; `at` must be in bounds.
+    fn node_at(&self, at: usize) -> NonNull<Node<T>> {
+        if at <= self.len / 2 {
+            let mut node = unsafe { self.head.unwrap_unchecked() };
+            for _ in 0..at {
+                node = unsafe { node.as_ref().next.unwrap_unchecked() };
+            }
+            node
+        } else {
+            let mut node = unsafe { self.tail.unwrap_unchecked() };
+            for _ in at + 1..self.len {
+                node = unsafe { node.as_ref().prev.unwrap_unchecked() };
+            }
+            node
+        }
+    }
+
+    // Detaches the `at` nodes in front of `node`, which must be the node at
+    // index `at`, and returns them as a list.
+    unsafe fn split_before(&mut self, mut node: NonNull<Node<T>>, at: usize) -> LinkedList<T> {
+        if at == 0 {
+            return LinkedList::new();
+        }
+
+        let mut tail = node.as_ref().prev.unwrap_unchecked();
+        tail.as_mut().next = None;
+        node.as_mut().prev = None;
+
+        let front = LinkedList {
+            head: self.head,
+            tail: Some(tail),
+            len: at,
+            version: 0,
+            id: AtomicUsize::new(0),
+            #[cfg(feature = "stats")]
+            stats: Stats::new(),
+        };
+
+        self.head = Some(node);
+        self.len -= at;
+        self.version += 1;
+
+        front
+    }
+
+    // The first `len % n` lists get one element more than the rest, and
+    // there are always exactly `n` of them, so some are empty if `n > len`.
+    pub fn split_into(mut self, n: usize) -> Vec<LinkedList<T>> {
+        assert!(n != 0, "Cannot split into zero lists");
+
+        traversal_span!("split_into", self.len);
+
+        let (size, extra) = (self.len / n, self.len % n);
+        let mut parts = Vec::with_capacity(n);
+        for part in 0..n - 1 {
+            let at = size + usize::from(part < extra);
+            if at == self.len {
+                parts.push(self.take());
+                continue;
+            }
+
+            let mut node = unsafe { self.head.unwrap_unchecked() };
+            for _ in 0..at {
+                node = unsafe { node.as_ref().next.unwrap_unchecked() };
+            }
+            record!(self, traversals, at);
+            parts.push(unsafe { self.split_before(node, at) });
+        }
+        parts.push(self);
+
+        parts
+    }
+
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        let Some(mut other_head) = other.head.take() else {
+            return;
+        };
+
+        match self.tail {
+            Some(mut tail) => {
+                unsafe { tail.as_mut() }.next = Some(other_head);
+                unsafe { other_head.as_mut() }.prev = Some(tail);
+            }
+            None => self.head = Some(other_head),
+        }
+        self.tail = other.tail.take();
+        self.len += mem::replace(&mut other.len, 0);
+        self.version += 1;
+        other.version += 1;
+    }
+
+    // The moved-out list keeps its version; `self` moves past it, so a
+    // version never repeats for the same list.
+    pub fn take(&mut self) -> LinkedList<T> {
+        let version = self.version;
+        let taken = mem::take(self);
+        self.version = version + 1;
+        taken
+    }
+
+    pub fn swap_with(&mut self, other: &mut LinkedList<T>) {
+        mem::swap(self, other);
+        let version = self.version.max(other.version) + 1;
+        self.version = version;
+        other.version = version;
+    }
+
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        assert!(
+            at <= self.len,
+            "Index out of bound: len is `{}` but index is `{}`",
+            self.len,
+            at
+        );
+
+        if at == 0 {
+            return self.take();
+        }
+
+        if at == self.len {
+            return LinkedList::new();
+        }
+
+        traversal_span!("split_off", at.min(self.len - 1 - at));
+
+        let mut node = self.node_at(at);
+        record!(self, traversals, at.min(self.len - 1 - at));
+
+        let mut tail = unsafe { node.as_ref().prev.unwrap_unchecked() };
+        unsafe { tail.as_mut() }.next = None;
+        unsafe { node.as_mut() }.prev = None;
+
+        let mut back = LinkedList::new();
+        back.head = Some(node);
+        back.tail = self.tail.replace(tail);
+        back.len = self.len - at;
+
+        self.len = at;
+        self.version += 1;
+
+        back
+    }
+
+    pub fn from_lists<I>(lists: I) -> LinkedList<T>
+    where
+        I: IntoIterator<Item = LinkedList<T>>,
+    {
+        let mut concat = LinkedList::new();
+        for mut list in lists {
+            concat.append(&mut list);
+        }
+
+        concat
+    }
+
+    pub fn from_fn<F>(n: usize, f: F) -> LinkedList<T>
+    where
+        F: FnMut(usize) -> T,
+    {
+        (0..n).map(f).collect()
+    }
+
+    pub fn repeat(key: T, n: usize) -> LinkedList<T>
+    where
+        T: Clone,
+    {
+        iter::repeat_n(key, n).collect()
+    }
+
+    pub fn repeat_with<F>(n: usize, f: F) -> LinkedList<T>
+    where
+        F: FnMut() -> T,
+    {
+        iter::repeat_with(f).take(n).collect()
+    }
+
+    pub fn push_front(&mut self, key: T) {
+        record!(self, pushes);
+        self.version += 1;
+
+        if self.head.is_none() {
+            let node = Some(self.alloc_node(Node::new(key)));
+            self.head = node;
+            self.tail = node;
+            self.len = 1;
+            return;
+        }
+
+        let node = Some(self.alloc_node(Node {
+            key,
+            prev: None,
+            next: self.head,
+        }));
+        unsafe { self.head.unwrap_unchecked().as_mut() }.prev = node;
+        self.head = node;
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, key: T) {
+        record!(self, pushes);
+        self.version += 1;
+
+        if self.tail.is_none() {
+            let node = Some(self.alloc_node(Node::new(key)));
+            self.head = node;
+            self.tail = node;
+            self.len = 1;
+            return;
+        }
+
+        let node = Some(self.alloc_node(Node {
+            key,
+            prev: self.tail,
+            next: None,
+        }));
+        unsafe { self.tail.unwrap_unchecked().as_mut() }.next = node;
+        self.tail = node;
+        self.len += 1;
+    }
+
+    pub fn extend_from_slice(&mut self, keys: &[T])
+    where
+        T: Clone,
+    {
+        self.extend(keys.iter().cloned());
+    }
+
+    // Each element goes in right after the one before it, so the elements
+    // end up at the front in the order the iterator yields them.
+    pub fn extend_front<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+        let Some(first) = iter.next() else {
+            return;
+        };
+
+        self.push_front(first);
+        let mut node = unsafe { self.head.unwrap_unchecked() };
+        for key in iter {
+            node = unsafe { self.push_after(node, key) };
+        }
+    }
+
+    pub fn push_at(&mut self, at: usize, key: T) {
+        assert!(
+            at <= self.len,
+            "Index out of bound: len is `{}` but index is `{}`",
+            self.len,
+            at
+        );
+
+        if at == 0 {
+            return self.push_front(key);
+        }
+
+        if at == self.len() {
+            return self.push_back(key);
+        }
+
+        traversal_span!("push_at", at);
+
+        let mut prev_node = unsafe { self.head.unwrap_unchecked() };
+        let mut post_node = unsafe { prev_node.as_ref().next.unwrap_unchecked() };
+
+        for _ in 1..at {
+            prev_node = post_node;
+            post_node = unsafe { post_node.as_ref().next.unwrap_unchecked() };
+        }
+        record!(self, traversals, at);
+        record!(self, pushes);
+
+        let node = Some(self.alloc_node(Node {
+            key,
+            prev: Some(prev_node),
+            next: Some(post_node),
+        }));
+
+        unsafe { prev_node.as_mut() }.next = node;
+        unsafe { post_node.as_mut() }.prev = node;
+
+        self.len += 1;
+        self.version += 1;
+    }
+
+    pub fn intersperse(&mut self, separator: T)
+    where
+        T: Clone,
+    {
+        traversal_span!("intersperse", self.len);
+
+        let mut node = self.head;
+
+        while let Some(mut prev_node) = node {
+            let Some(mut post_node) = unsafe { prev_node.as_ref() }.next else {
+                break;
+            };
+
+            let separator = Some(self.alloc_node(Node {
+                key: separator.clone(),
+                prev: Some(prev_node),
+                next: Some(post_node),
+            }));
+
+            unsafe { prev_node.as_mut() }.next = separator;
+            unsafe { post_node.as_mut() }.prev = separator;
+            self.len += 1;
+            self.version += 1;
+            record!(self, traversals);
+            record!(self, pushes);
+
+            node = Some(post_node);
+        }
+    }
+
+    // Counts a node the cache hands back as a reuse rather than an
+    // allocation.
+    #[cfg_attr(not(feature = "stats"), allow(unused_variables))]
+    fn alloc_node(&mut self, node: Node<T>) -> NonNull<Node<T>> {
+        let (node, reused) = Node::alloc_reused(node);
+        record!(self, reuses, reused);
+        record!(self, allocations, !reused);
+        node
+    }
+
+    // Unlinks the front node without freeing it.
+    fn pop_front_node(&mut self) -> Option<NonNull<Node<T>>> {
+        let mut node = self.head?;
+
+        self.head = unsafe { node.as_ref() }.next;
+        match self.head {
+            Some(mut head) => unsafe { head.as_mut() }.prev = None,
+            None => self.tail = None,
+        }
+        unsafe { node.as_mut() }.next = None;
+        self.len -= 1;
+        self.version += 1;
+
+        Some(node)
+    }
+
+    // Links `key` in right after `node`, which must belong to this list.
+    unsafe fn push_after(&mut self, mut node: NonNull<Node<T>>, key: T) -> NonNull<Node<T>> {
+        record!(self, pushes);
+
+        let next = node.as_ref().next;
+        let added = self.alloc_node(Node {
+            key,
+            prev: Some(node),
+            next,
+        });
+
+        node.as_mut().next = Some(added);
+        match next {
+            Some(mut next) => next.as_mut().prev = Some(added),
+            None => self.tail = Some(added),
+        }
+        self.len += 1;
+        self.version += 1;
+
+        added
+    }
+
+    // Unlinks and frees `node`, which must belong to this list.
+    unsafe fn unlink(&mut self, node: NonNull<Node<T>>) -> T {
+        record!(self, pops);
+
+        let (prev, next) = (node.as_ref().prev, node.as_ref().next);
+        match prev {
+            Some(mut prev) => prev.as_mut().next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(mut next) => next.as_mut().prev = prev,
+            None => self.tail = prev,
+        }
+        self.len -= 1;
+        self.version += 1;
+
+        Node::free(node)
+    }
+
+    // Relinks `node`, which must belong to this list, in at the front.
+    unsafe fn move_to_front(&mut self, mut node: NonNull<Node<T>>) {
+        let Some(mut prev) = node.as_ref().prev else {
+            return;
+        };
+
+        prev.as_mut().next = node.as_ref().next;
+        match node.as_ref().next {
+            Some(mut next) => next.as_mut().prev = Some(prev),
+            None => self.tail = Some(prev),
+        }
+
+        node.as_mut().prev = None;
+        node.as_mut().next = self.head;
+        self.head.unwrap_unchecked().as_mut().prev = Some(node);
+        self.head = Some(node);
+        self.version += 1;
+    }
+
+    // Links a detached node in at the back.
+    unsafe fn push_back_node(&mut self, mut node: NonNull<Node<T>>) {
+        node.as_mut().prev = self.tail;
+        node.as_mut().next = None;
+
+        match self.tail {
+            Some(mut tail) => tail.as_mut().next = Some(node),
+            None => self.head = Some(node),
+        }
+        self.tail = Some(node);
+        self.len += 1;
+        self.version += 1;
+    }
+
+    pub fn map<U, F>(mut self, mut f: F) -> LinkedList<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        traversal_span!("map", self.len);
+
+        let layout = Layout::new::<Node<T>>();
+        if layout != Layout::new::<Node<U>>() {
+            let mut mapped = LinkedList::new();
+            mapped.extend(self.into_iter().map(f));
+            return mapped;
+        }
+
+        // Frees a node whose key has been moved out if `f` unwinds.
+        struct Dealloc(NonNull<u8>, Layout);
+
+        impl Drop for Dealloc {
+            fn drop(&mut self) {
+                unsafe { alloc::alloc::dealloc(self.0.as_ptr(), self.1) };
+            }
+        }
+
+        let mut mapped = LinkedList::new();
+        while let Some(node) = self.pop_front_node() {
+            let key = unsafe { ptr::read(&node.as_ref().key) };
+
+            let guard = Dealloc(node.cast(), layout);
+            let key = f(key);
+            mem::forget(guard);
+
+            let node = node.cast::<Node<U>>();
+            unsafe {
+                ptr::write(node.as_ptr(), Node::new(key));
+                mapped.push_back_node(node);
+            }
+            record!(mapped, reuses);
+        }
+
+        mapped
+    }
+
+    // Hands back whatever the longer list had past the end of the shorter
+    // one; at most one of the two leftover lists is non-empty.
+    pub fn zip<U>(
+        mut self,
+        mut other: LinkedList<U>,
+    ) -> (LinkedList<(T, U)>, LinkedList<T>, LinkedList<U>) {
+        let len = self.len.min(other.len);
+        traversal_span!("zip", len);
+
+        let mut zipped = LinkedList::new();
+        for _ in 0..len {
+            let a = unsafe { self.pop_front().unwrap_unchecked() };
+            let b = unsafe { other.pop_front().unwrap_unchecked() };
+            zipped.push_back((a, b));
+        }
+
+        (zipped, self, other)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        record!(self, pops);
+        self.version += 1;
+
+        let prev = unsafe { tail.as_ref() }.prev;
+        let key = unsafe { Node::free(tail) };
+
+        if self.len == 1 {
+            self.head = None;
+            self.tail = None;
+            self.len = 0;
+        } else {
+            self.tail = prev;
+            self.tail = self.tail.map(|mut tail| {
+                unsafe { tail.as_mut() }.next = None;
+                tail
+            });
+            self.len -= 1;
+        }
+
+        Some(key)
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head?;
+        record!(self, pops);
+        self.version += 1;
+
+        let next = unsafe { head.as_ref() }.next;
+        let key = unsafe { Node::free(head) };
+
+        if self.len == 1 {
+            self.head = None;
+            self.tail = None;
+            self.len = 0;
+        } else {
+            self.head = next;
+            self.head = self.head.map(|mut head| {
+                unsafe { head.as_mut() }.prev = None;
+                head
+            });
+            self.len -= 1;
+        }
+
+        Some(key)
+    }
+
+    pub fn pop_at(&mut self, at: usize) -> Option<T> {
+        assert!(
+            at < self.len,
+            "Index out of bound: len is `{}` but index is `{}`",
+            self.len,
+            at
+        );
+
+        if at == 0 {
+            return self.pop_front();
+        }
+
+        if at == self.len - 1 {
+            return self.pop_back();
+        }
+
+        traversal_span!("pop_at", at);
+
+        let mut prev_node = unsafe { self.head.unwrap_unchecked() };
+        let mut post_node = unsafe {
+            prev_node
+                .as_ref()
+                .next
+                .unwrap_unchecked()
+                .as_ref()
+                .next
+                .unwrap_unchecked()
+        };
+
+        for _ in 1..at {
+            prev_node = unsafe { prev_node.as_ref().next.unwrap_unchecked() };
+            post_node = unsafe { post_node.as_ref().next.unwrap_unchecked() };
+        }
+        record!(self, traversals, at);
+        record!(self, pops);
+
+        let node = unsafe { prev_node.as_ref().next.unwrap_unchecked() };
+        let key = unsafe { Node::free(node) };
+
+        unsafe { prev_node.as_mut() }.next = Some(post_node);
+        unsafe { post_node.as_mut() }.prev = Some(prev_node);
+
+        self.len -= 1;
+        self.version += 1;
+
+        Some(key)
+    }
+
+    // Removes every listed index in one walk. Indices are sorted and
+    // deduplicated first and refer to positions before any removal; the
+    // removed elements come back in index order.
+    pub fn remove_indices<I>(&mut self, indices: I) -> Vec<T>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let mut indices: Vec<usize> = indices.into_iter().collect();
+        indices.sort_unstable();
+        indices.dedup();
+
+        let Some(&last) = indices.last() else {
+            return Vec::new();
+        };
+        assert!(
+            last < self.len,
+            "Index out of bound: len is `{}` but index is `{}`",
+            self.len,
+            last
+        );
+
+        traversal_span!("remove_indices", last);
+        record!(self, traversals, last);
+
+        let mut removed = Vec::with_capacity(indices.len());
+        let mut node = self.head;
+        let mut at = 0;
+        for index in indices {
+            while at < index {
+                node = unsafe { node.unwrap_unchecked().as_ref() }.next;
+                at += 1;
+            }
+
+            let curr = unsafe { node.unwrap_unchecked() };
+            node = unsafe { curr.as_ref() }.next;
+            at += 1;
+            removed.push(unsafe { self.unlink(curr) });
+        }
+
+        removed
+    }
+
+    pub fn get_many_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        let mut order: [usize; N] = array::from_fn(|slot| slot);
+        order.sort_unstable_by_key(|&slot| indices[slot]);
+
+        if order
+            .windows(2)
+            .any(|pair| indices[pair[0]] == indices[pair[1]])
+        {
+            return None;
+        }
+
+        if order.last().is_some_and(|&slot| indices[slot] >= self.len) {
+            return None;
+        }
+
+        traversal_span!(
+            "get_many_mut",
+            order.last().map_or(0, |&slot| indices[slot])
+        );
+
+        let mut nodes = [None; N];
+        let mut node = self.head;
+        let mut at = 0;
+        for slot in order {
+            while at < indices[slot] {
+                node = unsafe { node.unwrap_unchecked().as_ref() }.next;
+                at += 1;
+            }
+            nodes[slot] = node;
+        }
+        record!(self, traversals, at);
+
+        Some(nodes.map(|node| &mut unsafe { node.unwrap_unchecked().as_mut() }.key))
+    }
+
+    pub fn contains(&self, key: &T) -> bool
+    where
+        T: PartialEq<T>,
+    {
+        traversal_span!("contains", self.len);
+
+        for x in self.iter() {
+            if *x == *key {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub fn position_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        traversal_span!("position_of", self.len);
+        self.iter().position(|x| x.borrow() == key)
+    }
+
+    pub fn count_of<Q>(&self, key: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        traversal_span!("count_of", self.len);
+        self.iter().filter(|x| (*x).borrow() == key).count()
+    }
+
+    pub fn starts_with<'a, I>(&self, prefix: I) -> bool
+    where
+        T: PartialEq<T> + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        let mut iter = self.iter();
+        prefix.into_iter().all(|x| iter.next() == Some(x))
+    }
+
+    pub fn ends_with<'a, I>(&self, suffix: I) -> bool
+    where
+        T: PartialEq<T> + 'a,
+        I: IntoIterator<Item = &'a T>,
+        I::IntoIter: DoubleEndedIterator,
+    {
+        let mut iter = self.iter();
+        suffix
+            .into_iter()
+            .rev()
+            .all(|x| iter.next_back() == Some(x))
+    }
+
+    pub fn eq_by<I, F>(&self, other: I, mut eq: F) -> bool
+    where
+        I: IntoIterator,
+        F: FnMut(&T, I::Item) -> bool,
+    {
+        let mut other = other.into_iter();
+        for x in self.iter() {
+            let Some(y) = other.next() else {
+                return false;
+            };
+
+            if !eq(x, y) {
+                return false;
+            }
+        }
+
+        other.next().is_none()
+    }
+
+    // Lexicographic, like `Ord` for slices: the first unequal pair decides,
+    // and otherwise the shorter side is less.
+    pub fn cmp_by<I, F>(&self, other: I, mut cmp: F) -> Ordering
+    where
+        I: IntoIterator,
+        F: FnMut(&T, I::Item) -> Ordering,
+    {
+        let mut other = other.into_iter();
+        for x in self.iter() {
+            let Some(y) = other.next() else {
+                return Ordering::Greater;
+            };
+
+            match cmp(x, y) {
+                Ordering::Equal => {}
+                ordering => return ordering,
+            }
+        }
+
+        match other.next() {
+            Some(_) => Ordering::Less,
+            None => Ordering::Equal,
+        }
+    }
+
+    // Ties resolve like `Iterator`: the minimum is the first of equal
+    // elements and the maximum the last. Plain `min` and `max` would be
+    // shadowed by `Ord::min` and `Ord::max` on the list itself.
+    pub fn min_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().min()
+    }
+
+    pub fn max_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().max()
+    }
+
+    pub fn min_by_key<K, F>(&self, mut f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.iter().min_by_key(|x| f(x))
+    }
+
+    pub fn max_by_key<K, F>(&self, mut f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.iter().max_by_key(|x| f(x))
+    }
+
+    // The node `min_by_key` or `max_by_key` picks, found in one walk so the
+    // `_mut` and `remove_` forms below need no second search.
+    fn node_by_key<K, F>(&self, mut f: F, max: bool) -> Option<NonNull<Node<T>>>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        traversal_span!("node_by_key", self.len);
+
+        let mut best: Option<(K, NonNull<Node<T>>)> = None;
+        let mut node = self.head;
+        while let Some(curr) = node {
+            let key = f(&unsafe { curr.as_ref() }.key);
+            let better = best.as_ref().is_none_or(|(best, _)| match max {
+                true => key >= *best,
+                false => key < *best,
+            });
+            if better {
+                best = Some((key, curr));
+            }
+            node = unsafe { curr.as_ref() }.next;
+        }
+
+        best.map(|(_, node)| node)
+    }
+
+    pub fn min_by_key_mut<K, F>(&mut self, f: F) -> Option<&mut T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let node = self.node_by_key(f, false)?;
+        Some(&mut unsafe { &mut *node.as_ptr() }.key)
+    }
+
+    pub fn max_by_key_mut<K, F>(&mut self, f: F) -> Option<&mut T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let node = self.node_by_key(f, true)?;
+        Some(&mut unsafe { &mut *node.as_ptr() }.key)
+    }
+
+    pub fn remove_min_by_key<K, F>(&mut self, f: F) -> Option<T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let node = self.node_by_key(f, false)?;
+        Some(unsafe { self.unlink(node) })
+    }
+
+    pub fn remove_max_by_key<K, F>(&mut self, f: F) -> Option<T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let node = self.node_by_key(f, true)?;
+        Some(unsafe { self.unlink(node) })
+    }
+
+    // Knuth–Morris–Pratt, so the list is walked once whatever the needle.
+    pub fn contains_slice<'a, I>(&self, needle: I) -> bool
+    where
+        T: PartialEq<T> + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        let needle: Vec<&T> = needle.into_iter().collect();
+        if needle.is_empty() {
+            return true;
+        }
+
+        traversal_span!("contains_slice", self.len);
+
+        let mut fallback = vec![0; needle.len()];
+        let mut matched = 0;
+        for at in 1..needle.len() {
+            while matched > 0 && needle[at] != needle[matched] {
+                matched = fallback[matched - 1];
+            }
+            if needle[at] == needle[matched] {
+                matched += 1;
+            }
+            fallback[at] = matched;
+        }
+
+        matched = 0;
+        for x in self.iter() {
+            while matched > 0 && x != needle[matched] {
+                matched = fallback[matched - 1];
+            }
+            if x == needle[matched] {
+                matched += 1;
+            }
+            if matched == needle.len() {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+impl<T> LinkedList<LinkedList<T>> {
+    pub fn concat(self) -> LinkedList<T> {
+        LinkedList::from_lists(self)
+    }
+}
+
+impl<A, B> LinkedList<(A, B)> {
+    pub fn unzip(self) -> (LinkedList<A>, LinkedList<B>) {
+        traversal_span!("unzip", self.len);
+
+        let mut left = LinkedList::new();
+        let mut right = LinkedList::new();
+        for (a, b) in self {
+            left.push_back(a);
+            right.push_back(b);
+        }
+
+        (left, right)
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = LinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
+    fn from(keys: [T; N]) -> Self {
+        LinkedList::from_iter(keys)
+    }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        traversal_span!("clone", self.len);
+
+        let mut list = LinkedList::new();
+        list.extend(self.iter().cloned());
+        list
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        traversal_span!("clone_from", source.len);
+
+        while self.len > source.len {
+            self.pop_back();
+        }
+
+        let mut source = source.iter();
+        for (key, source) in self.iter_mut().zip(source.by_ref()) {
+            key.clone_from(source);
+        }
+        record!(self, reuses, self.len);
+        self.version += 1;
+        self.extend(source.cloned());
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for key in iter {
+            self.push_back(key);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other)
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+impl<T: PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other)
+    }
+}
+
+impl<T: Ord> Ord for LinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other)
+    }
+}
+
+// Prefixed with the length so that a sequence of lists hashes unambiguously,
+// like std's collections do.
+impl<T: Hash> Hash for LinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        for key in self {
+            key.hash(state);
+        }
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        if self.head.is_none() {
+            return;
+        }
+
+        while !self.is_empty() {
+            let to_free = unsafe { self.head.unwrap_unchecked() };
+            self.head = unsafe { to_free.as_ref() }.next;
+            self.len -= 1;
+            drop(unsafe { Node::free(to_free) });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        panic::{self, AssertUnwindSafe},
+        rc::Rc,
+    };
+
+    use super::*;
+
+    fn nodes<T>(list: &LinkedList<T>) -> Vec<*const u8> {
+        let mut nodes = Vec::new();
+        let mut node = list.head;
+        while let Some(curr) = node {
+            nodes.push(curr.as_ptr().cast_const().cast());
+            node = unsafe { curr.as_ref() }.next;
+        }
+
+        nodes
+    }
+
+    // Counts its drops into a shared cell.
+    struct Counted(i32, Rc<Cell<usize>>);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    #[test]
+    fn map_reuses_nodes_when_layouts_match() {
+        let list: LinkedList<u32> = (0..5).collect();
+        let before = nodes(&list);
+
+        let mapped = list.map(|key| key as i32 - 2);
+        assert_eq!(nodes(&mapped), before);
+        assert!(mapped.eq_by(-2..3, |a, b| *a == b));
+        mapped.debug_validate();
+    }
+
+    #[test]
+    fn map_reallocates_when_layouts_differ() {
+        let list: LinkedList<u8> = (0..5).collect();
+        let mapped = list.map(|key| [u64::from(key); 4]);
+        assert_eq!(mapped.len(), 5);
+        assert_eq!(mapped.back(), Some(&[4; 4]));
+        mapped.debug_validate();
+    }
+
+    #[test]
+    fn map_drops_everything_once_when_the_closure_panics() {
+        let drops = Rc::new(Cell::new(0));
+        let list: LinkedList<Counted> = (0..6).map(|key| Counted(key, drops.clone())).collect();
+
+        // Same layout, so this takes the node-reusing path.
+        assert_eq!(
+            Layout::new::<Node<Counted>>(),
+            Layout::new::<Node<[u64; 2]>>()
+        );
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            list.map(|Counted(key, _)| {
+                assert!(key != 3, "stop at 3");
+                [key as u64; 2]
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 6);
+    }
+
+    // Every sequence over `0..3` of up to six elements.
+    fn small_vecs() -> impl Iterator<Item = Vec<u8>> {
+        (0..=6).flat_map(|len| {
+            (0..3usize.pow(len)).map(move |mut code| {
+                (0..len)
+                    .map(|_| {
+                        let digit = (code % 3) as u8;
+                        code /= 3;
+                        digit
+                    })
+                    .collect()
+            })
+        })
+    }
+
+    fn collect<'a>(parts: impl Iterator<Item = Iter<'a, u8>>) -> Vec<Vec<u8>> {
+        parts.map(|part| part.copied().collect()).collect()
+    }
+
+    #[test]
+    fn chunks_match_slice_chunks() {
+        for vec in small_vecs() {
+            let list: LinkedList<u8> = vec.iter().copied().collect();
+            for size in 1..=7 {
+                let expected: Vec<Vec<u8>> = vec.chunks(size).map(<[u8]>::to_vec).collect();
+                assert_eq!(list.chunks(size).len(), expected.len());
+                assert_eq!(collect(list.chunks(size)), expected);
+
+                let reversed: Vec<Vec<u8>> = list
+                    .chunks(size)
+                    .map(|chunk| chunk.rev().copied().collect())
+                    .collect();
+                let expected_reversed: Vec<Vec<u8>> = vec
+                    .chunks(size)
+                    .map(|chunk| chunk.iter().rev().copied().collect())
+                    .collect();
+                assert_eq!(reversed, expected_reversed);
+            }
+        }
+    }
+
+    #[test]
+    fn chunk_by_matches_slice_chunk_by() {
+        let predicates: [fn(&u8, &u8) -> bool; 3] = [|a, b| a == b, |a, b| a <= b, |_, _| false];
+        for vec in small_vecs() {
+            let list: LinkedList<u8> = vec.iter().copied().collect();
+            for predicate in predicates {
+                let expected: Vec<Vec<u8>> = vec.chunk_by(predicate).map(<[u8]>::to_vec).collect();
+                assert_eq!(collect(list.chunk_by(predicate)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn split_adapters_match_slice_split() {
+        let is_zero = |key: &u8| *key == 0;
+        for vec in small_vecs() {
+            let list: LinkedList<u8> = vec.iter().copied().collect();
+            let expected: Vec<Vec<u8>> = vec.split(is_zero).map(<[u8]>::to_vec).collect();
+            assert_eq!(collect(list.split(is_zero)), expected);
+
+            let into: Vec<Vec<u8>> = list
+                .clone()
+                .into_split(is_zero)
+                .map(|part| part.into_iter().collect())
+                .collect();
+            assert_eq!(into, expected);
+
+            for n in 0..=4 {
+                let expected: Vec<Vec<u8>> = vec.splitn(n, is_zero).map(<[u8]>::to_vec).collect();
+                assert_eq!(collect(list.splitn(n, is_zero)), expected);
+
+                let into: Vec<Vec<u8>> = list
+                    .clone()
+                    .into_splitn(n, is_zero)
+                    .map(|part| {
+                        part.debug_validate();
+                        part.into_iter().collect()
+                    })
+                    .collect();
+                assert_eq!(into, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn split_segments_iterate_from_both_ends() {
+        let list = LinkedList::from([1, 2, 0, 0, 3, 4, 5]);
+        let segments: Vec<Vec<i32>> = list
+            .split(|key| *key == 0)
+            .map(|segment| segment.rev().copied().collect())
+            .collect();
+        assert_eq!(segments, [vec![2, 1], vec![], vec![5, 4, 3]]);
+    }
+
+    #[test]
+    fn iter_pairs_matches_slice_windows() {
+        for vec in small_vecs() {
+            let list: LinkedList<u8> = vec.iter().copied().collect();
+            let expected: Vec<(u8, u8)> = vec.windows(2).map(|pair| (pair[0], pair[1])).collect();
+
+            assert_eq!(list.iter_pairs().len(), expected.len());
+            assert_eq!(
+                list.iter_pairs().map(|(a, b)| (*a, *b)).collect::<Vec<_>>(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn min_and_max_element_pick_first_and_last_ties() {
+        let empty: LinkedList<(u8, u8)> = LinkedList::new();
+        assert_eq!(empty.min_element(), None);
+        assert_eq!(empty.max_by_key(|pair| pair.0), None);
+
+        let list = LinkedList::from([3, 1, 4, 1, 5, 9, 2, 6, 5]);
+        assert_eq!(list.min_element(), Some(&1));
+        assert_eq!(list.max_element(), Some(&9));
+
+        let pairs = LinkedList::from([(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]);
+        assert_eq!(pairs.min_by_key(|pair| pair.0), Some(&(1, 'b')));
+        assert_eq!(pairs.max_by_key(|pair| pair.0), Some(&(2, 'c')));
+        assert!(std::ptr::eq(
+            pairs.min_by_key(|pair| pair.0).unwrap(),
+            pairs.iter().nth(1).unwrap()
+        ));
+    }
+
+    #[test]
+    fn min_and_max_element_match_iterator() {
+        for vec in small_vecs() {
+            let list: LinkedList<u8> = vec.iter().copied().collect();
+            assert_eq!(list.min_element(), vec.iter().min());
+            assert_eq!(list.max_element(), vec.iter().max());
+            assert_eq!(
+                list.min_by_key(|key| *key / 2),
+                vec.iter().min_by_key(|key| **key / 2)
+            );
+            assert_eq!(
+                list.max_by_key(|key| *key / 2),
+                vec.iter().max_by_key(|key| **key / 2)
+            );
+        }
+    }
+
+    #[test]
+    fn eq_by_and_cmp_by_match_slice_comparisons() {
+        for a in small_vecs().filter(|vec| vec.len() <= 3) {
+            let list: LinkedList<u8> = a.iter().copied().collect();
+            for b in small_vecs().filter(|vec| vec.len() <= 3) {
+                let other: Vec<u32> = b.iter().map(|&key| u32::from(key)).collect();
+                assert_eq!(list.eq_by(&other, |x, y| u32::from(*x) == *y), a == b);
+                assert_eq!(list.cmp_by(&other, |x, y| u32::from(*x).cmp(y)), a.cmp(&b));
+            }
+        }
+    }
+
+    #[test]
+    fn cmp_by_stops_at_the_first_difference() {
+        let list = LinkedList::from([1, 2, 3]);
+        let mut calls = 0;
+        let ordering = list.cmp_by([1, 5, 0], |x, y: i32| {
+            calls += 1;
+            x.cmp(&y)
+        });
+        assert_eq!((ordering, calls), (Ordering::Less, 2));
+        assert!(!list.eq_by([1, 2], |x, y: i32| *x == y));
+    }
+
+    #[test]
+    fn position_of_and_count_of_match_iterator() {
+        for vec in small_vecs() {
+            let list: LinkedList<u8> = vec.iter().copied().collect();
+            for key in 0..3 {
+                assert_eq!(list.position_of(&key), vec.iter().position(|x| *x == key));
+                assert_eq!(
+                    list.count_of(&key),
+                    vec.iter().filter(|x| **x == key).count()
+                );
+            }
+        }
+
+        let names = LinkedList::from(["a".to_string(), "b".to_string(), "a".to_string()]);
+        assert_eq!(names.position_of("b"), Some(1));
+        assert_eq!(names.count_of("a"), 2);
+        assert_eq!(names.position_of("c"), None);
+    }
+
+    #[test]
+    fn remove_indices_matches_removing_from_a_vec() {
+        for vec in small_vecs() {
+            // Each digit of `vec` picks an index (mod its length) to remove,
+            // so the requests come unsorted and with repeats.
+            let len = vec.len();
+            let indices: Vec<usize> = vec
+                .iter()
+                .map(|&key| usize::from(key) * 2 % len.max(1))
+                .collect();
+            let mut list: LinkedList<u8> = vec.iter().copied().collect();
+            let removed = list.remove_indices(indices.iter().copied());
+            list.debug_validate();
+
+            let mut sorted = indices;
+            sorted.sort_unstable();
+            sorted.dedup();
+            let expected: Vec<u8> = sorted.iter().map(|&at| vec[at]).collect();
+            let kept: Vec<u8> = (0..len)
+                .filter(|at| !sorted.contains(at))
+                .map(|at| vec[at])
+                .collect();
+
+            assert_eq!(removed, expected);
+            assert!(list.iter().eq(&kept));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bound")]
+    fn remove_indices_past_the_end_panics() {
+        let mut list = LinkedList::from([1, 2, 3]);
+        list.remove_indices([0, 3]);
+    }
+
+    #[test]
+    fn extend_front_keeps_iterator_order() {
+        let mut list = LinkedList::from([4, 5]);
+        list.extend_front([1, 2, 3]);
+        list.extend_front([]);
+        list.debug_validate();
+        assert!(list.iter().eq(&[1, 2, 3, 4, 5]));
+
+        let mut empty = LinkedList::new();
+        empty.extend_front([1, 2]);
+        empty.extend_from_slice(&[3, 4]);
+        empty.debug_validate();
+        assert!(empty.iter().eq(&[1, 2, 3, 4]));
+        assert_eq!(empty.back(), Some(&4));
+    }
+
+    #[test]
+    fn generated_lists_have_the_requested_keys() {
+        let squares = LinkedList::from_fn(5, |at| at * at);
+        assert!(squares.iter().eq(&[0, 1, 4, 9, 16]));
+        assert!(LinkedList::from_fn(0, |_| -> u8 { unreachable!() }).is_empty());
+
+        let words = LinkedList::repeat("ab".to_string(), 3);
+        assert_eq!(words.len(), 3);
+        assert!(words.iter().all(|word| word == "ab"));
+
+        let mut next = 0;
+        let counted = LinkedList::repeat_with(4, || {
+            next += 1;
+            next
+        });
+        counted.debug_validate();
+        assert!(counted.iter().eq(&[1, 2, 3, 4]));
+        assert_eq!(next, 4);
+    }
+
+    #[test]
+    fn split_into_gives_n_balanced_parts_in_order() {
+        for vec in small_vecs() {
+            for n in 1..=vec.len() + 2 {
+                let list: LinkedList<u8> = vec.iter().copied().collect();
+                let parts = list.split_into(n);
+                assert_eq!(parts.len(), n);
+
+                let lens: Vec<usize> = parts.iter().map(LinkedList::len).collect();
+                let (size, extra) = (vec.len() / n, vec.len() % n);
+                assert!(lens
+                    .iter()
+                    .enumerate()
+                    .all(|(at, &len)| len == size + usize::from(at < extra)));
+
+                for part in &parts {
+                    part.debug_validate();
+                }
+                let joined: Vec<u8> = parts.into_iter().flatten().collect();
+                assert_eq!(joined, vec);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "zero lists")]
+    fn split_into_zero_panics() {
+        LinkedList::from([1]).split_into(0);
+    }
+
+    #[test]
+    fn zip_hands_back_the_longer_tail() {
+        let (zipped, left, right) =
+            LinkedList::from([1, 2, 3, 4]).zip(LinkedList::from(['a', 'b']));
+        zipped.debug_validate();
+        left.debug_validate();
+        assert!(zipped.iter().eq(&[(1, 'a'), (2, 'b')]));
+        assert!(left.iter().eq(&[3, 4]));
+        assert!(right.is_empty());
+
+        let (zipped, left, right) = LinkedList::<u8>::new().zip(LinkedList::from([1]));
+        assert!(zipped.is_empty() && left.is_empty());
+        assert!(right.iter().eq(&[1]));
+    }
+
+    #[test]
+    fn unzip_undoes_zip() {
+        let pairs = LinkedList::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+        let (numbers, letters) = pairs.clone().unzip();
+        numbers.debug_validate();
+        letters.debug_validate();
+        assert!(numbers.iter().eq(&[1, 2, 3]));
+        assert!(letters.iter().eq(&['a', 'b', 'c']));
+
+        let (zipped, _, _) = numbers.zip(letters);
+        assert_eq!(zipped, pairs);
+
+        let (left, right) = LinkedList::<(u8, u8)>::new().unzip();
+        assert!(left.is_empty() && right.is_empty());
+    }
+
+    #[test]
+    fn behaves_like_std_linked_list() {
+        use std::{collections, hash::DefaultHasher};
+
+        fn hash_of(key: &impl Hash) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            key.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let lists: Vec<(LinkedList<u8>, collections::LinkedList<u8>)> = small_vecs()
+            .filter(|vec| vec.len() <= 4)
+            .map(|vec| (vec.iter().copied().collect(), vec.into_iter().collect()))
+            .collect();
+
+        for (list, std_list) in &lists {
+            assert_eq!(format!("{list:?}"), format!("{std_list:?}"));
+            assert_eq!(format!("{:?}", list.iter()), format!("{list:?}"));
+            assert_eq!(hash_of(list), hash_of(std_list));
+            assert_eq!(list.front(), std_list.front());
+            assert_eq!(list.back(), std_list.back());
+
+            for at in 0..=list.len() {
+                let (mut list, mut std_list) = (list.clone(), std_list.clone());
+                let (back, std_back) = (list.split_off(at), std_list.split_off(at));
+                list.debug_validate();
+                back.debug_validate();
+                assert!(list.iter().eq(&std_list) && back.iter().eq(&std_back));
+            }
+
+            for (other, std_other) in &lists {
+                assert_eq!(list.cmp(other), std_list.cmp(std_other));
+                assert_eq!(list == other, std_list == std_other);
+
+                let mut target = other.clone();
+                target.clone_from(list);
+                target.debug_validate();
+                assert_eq!(&target, list);
+            }
+        }
+    }
+
+    #[test]
+    fn nth_and_nth_back_match_slice_iterators() {
+        for vec in small_vecs() {
+            let mut list: LinkedList<u8> = vec.iter().copied().collect();
+            for n in 0..=vec.len() + 1 {
+                for back in 0..=vec.len() + 1 {
+                    let mut expected = vec.iter();
+                    let mut iter = list.iter();
+                    assert_eq!(iter.nth(n), expected.nth(n));
+                    assert_eq!(iter.nth_back(back), expected.nth_back(back));
+                    assert!(iter.eq(expected));
+
+                    let mut expected = vec.clone();
+                    let mut expected = expected.iter_mut();
+                    let mut iter = list.iter_mut();
+                    assert_eq!(iter.nth_back(back), expected.nth_back(back));
+                    assert_eq!(iter.nth(n), expected.nth(n));
+                    assert_eq!(iter.len(), expected.len());
+                }
+            }
+
+            assert_eq!(list.iter().last(), vec.last());
+            assert_eq!(list.iter().count(), vec.len());
+            assert_eq!(list.into_iter().last(), vec.last().copied());
+        }
+    }
+
+    #[test]
+    fn remove_and_mut_forms_pick_the_same_extremum() {
+        for vec in small_vecs() {
+            let key = |x: &u8| *x / 2;
+            let list: LinkedList<u8> = vec.iter().copied().collect();
+
+            let at = vec
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, x)| key(x))
+                .map(|(at, _)| at);
+            let mut removed = list.clone();
+            assert_eq!(removed.remove_min_by_key(key), at.map(|at| vec[at]));
+            removed.debug_validate();
+            let mut expected = vec.clone();
+            if let Some(at) = at {
+                expected.remove(at);
+            }
+            assert!(removed.iter().eq(&expected));
+
+            let at = vec
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, x)| key(x))
+                .map(|(at, _)| at);
+            let mut removed = list.clone();
+            assert_eq!(removed.remove_max_by_key(key), at.map(|at| vec[at]));
+            removed.debug_validate();
+            let mut expected = vec.clone();
+            if let Some(at) = at {
+                expected.remove(at);
+            }
+            assert!(removed.iter().eq(&expected));
+        }
+    }
+
+    #[test]
+    fn mut_forms_edit_the_extremum_in_place() {
+        let mut list = LinkedList::from([(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]);
+        list.min_by_key_mut(|pair| pair.0).unwrap().1 = 'x';
+        list.max_by_key_mut(|pair| pair.0).unwrap().1 = 'y';
+        assert!(list.iter().eq(&[(2, 'a'), (1, 'x'), (2, 'y'), (1, 'd')]));
+
+        let mut empty: LinkedList<u8> = LinkedList::new();
+        assert_eq!(empty.min_by_key_mut(|key| *key), None);
+        assert_eq!(empty.remove_max_by_key(|key| *key), None);
+    }
+}
@@ -0,0 +1,165 @@
+use core::ops::Deref;
+
+use crate::LinkedList;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Change<'a, T> {
+    Inserted { at: usize, key: &'a T },
+    Removed { at: usize, key: &'a T },
+}
+
+pub trait Observer<T> {
+    fn notify(&mut self, change: Change<'_, T>);
+}
+
+impl<T, F> Observer<T> for F
+where
+    F: FnMut(Change<'_, T>),
+{
+    fn notify(&mut self, change: Change<'_, T>) {
+        self(change)
+    }
+}
+
+// Insertions are reported just before the element is linked in, removals
+// just after it is unlinked; either way `at` is its index while in the list.
+pub struct ObservedList<T, O> {
+    list: LinkedList<T>,
+    observer: O,
+}
+
+impl<T, O: Observer<T>> ObservedList<T, O> {
+    pub const fn new(observer: O) -> ObservedList<T, O> {
+        ObservedList {
+            list: LinkedList::new(),
+            observer,
+        }
+    }
+
+    pub fn into_inner(self) -> (LinkedList<T>, O) {
+        (self.list, self.observer)
+    }
+
+    pub fn observer(&self) -> &O {
+        &self.observer
+    }
+
+    pub fn observer_mut(&mut self) -> &mut O {
+        &mut self.observer
+    }
+
+    pub fn push_front(&mut self, key: T) {
+        self.observer.notify(Change::Inserted { at: 0, key: &key });
+        self.list.push_front(key);
+    }
+
+    pub fn push_back(&mut self, key: T) {
+        let at = self.list.len();
+        self.observer.notify(Change::Inserted { at, key: &key });
+        self.list.push_back(key);
+    }
+
+    pub fn push_at(&mut self, at: usize, key: T) {
+        assert!(
+            at <= self.list.len(),
+            "Index out of bound: len is `{}` but index is `{}`",
+            self.list.len(),
+            at
+        );
+
+        self.observer.notify(Change::Inserted { at, key: &key });
+        self.list.push_at(at, key);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let key = self.list.pop_front()?;
+        self.observer.notify(Change::Removed { at: 0, key: &key });
+
+        Some(key)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let key = self.list.pop_back()?;
+        let at = self.list.len();
+        self.observer.notify(Change::Removed { at, key: &key });
+
+        Some(key)
+    }
+
+    pub fn pop_at(&mut self, at: usize) -> Option<T> {
+        let key = self.list.pop_at(at)?;
+        self.observer.notify(Change::Removed { at, key: &key });
+
+        Some(key)
+    }
+}
+
+impl<T, O> Deref for ObservedList<T, O> {
+    type Target = LinkedList<T>;
+    fn deref(&self) -> &LinkedList<T> {
+        &self.list
+    }
+}
+
+impl<T, O: Observer<T> + Default> Default for ObservedList<T, O> {
+    fn default() -> Self {
+        Self::new(O::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Replays every change onto a `Vec`, which has to end up matching the
+    // list.
+    #[derive(Default)]
+    struct Mirror(Vec<i32>);
+
+    impl Observer<i32> for Mirror {
+        fn notify(&mut self, change: Change<'_, i32>) {
+            match change {
+                Change::Inserted { at, key } => self.0.insert(at, *key),
+                Change::Removed { at, key } => assert_eq!(self.0.remove(at), *key),
+            }
+        }
+    }
+
+    #[test]
+    fn a_mirror_replaying_the_changes_matches_the_list() {
+        let mut list = ObservedList::<i32, Mirror>::default();
+        list.push_back(2);
+        list.push_front(0);
+        list.push_at(1, 1);
+        list.push_back(3);
+        assert_eq!(list.pop_at(2), Some(2));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(0));
+        list.push_at(1, 4);
+
+        assert_eq!(list.observer().0, [1, 4]);
+        let (list, mirror) = list.into_inner();
+        assert!(list.iter().eq(&mirror.0));
+    }
+
+    #[test]
+    fn closures_observe_and_empty_pops_report_nothing() {
+        let mut changes = Vec::new();
+        let mut list = ObservedList::new(|change: Change<'_, i32>| {
+            changes.push(match change {
+                Change::Inserted { at, key } => (true, at, *key),
+                Change::Removed { at, key } => (false, at, *key),
+            })
+        });
+
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+        list.push_back(7);
+        list.push_back(8);
+        assert_eq!(list.pop_back(), Some(8));
+        assert_eq!(list.len(), 1);
+        drop(list);
+
+        assert_eq!(changes, [(true, 0, 7), (true, 1, 8), (false, 1, 8)]);
+    }
+}
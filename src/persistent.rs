@@ -0,0 +1,189 @@
+use core::iter::FusedIterator;
+
+use crate::atomic::Arc;
+
+struct Cons<T> {
+    key: T,
+    next: Option<Arc<Cons<T>>>,
+}
+
+pub struct PersistentList<T> {
+    head: Option<Arc<Cons<T>>>,
+    len: usize,
+}
+
+pub struct Iter<'a, T> {
+    head: Option<&'a Cons<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.head?;
+
+        self.head = node.next.as_deref();
+        self.len -= 1;
+
+        Some(&node.key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> PersistentList<T> {
+    pub const fn new() -> PersistentList<T> {
+        PersistentList { head: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_deref().map(|node| &node.key)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head.as_deref(),
+            len: self.len,
+        }
+    }
+
+    pub fn push_front(&self, key: T) -> PersistentList<T> {
+        PersistentList {
+            head: Some(Arc::new(Cons {
+                key,
+                next: self.head.clone(),
+            })),
+            len: self.len + 1,
+        }
+    }
+
+    pub fn tail(&self) -> PersistentList<T> {
+        match self.head.as_deref() {
+            Some(node) => PersistentList {
+                head: node.next.clone(),
+                len: self.len - 1,
+            },
+            None => PersistentList::new(),
+        }
+    }
+
+    pub fn contains(&self, key: &T) -> bool
+    where
+        T: PartialEq<T>,
+    {
+        self.iter().any(|x| *x == *key)
+    }
+}
+
+impl<T> Clone for PersistentList<T> {
+    fn clone(&self) -> Self {
+        PersistentList {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for PersistentList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for PersistentList<T> {
+    fn drop(&mut self) {
+        // Unlink uniquely owned cells one at a time so that dropping a long
+        // chain does not recurse once per cell; stop at the first shared one.
+        let mut head = self.head.take();
+        while let Some(node) = head {
+            match Arc::try_unwrap(node) {
+                Ok(mut node) => head = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::*;
+
+    struct Counted(i32, Rc<Cell<usize>>);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    fn cells(list: &PersistentList<i32>, skip: usize) -> Vec<*const i32> {
+        list.iter()
+            .skip(skip)
+            .map(|key| key as *const i32)
+            .collect()
+    }
+
+    #[test]
+    fn versions_share_their_common_tail() {
+        let base = PersistentList::new().push_front(2).push_front(1);
+        let left = base.push_front(10);
+        let right = base.tail().push_front(20);
+
+        assert!(base.iter().eq(&[1, 2]));
+        assert!(left.iter().eq(&[10, 1, 2]));
+        assert!(right.iter().eq(&[20, 2]));
+        assert_eq!(
+            (left.len(), right.len(), base.tail().tail().len()),
+            (3, 2, 0)
+        );
+
+        // The cells behind the new fronts are the original ones, not copies.
+        assert_eq!(cells(&left, 1), cells(&base, 0));
+        assert_eq!(cells(&right, 1), cells(&base, 1));
+    }
+
+    #[test]
+    fn a_cell_is_dropped_once_with_its_last_version() {
+        let drops = Rc::new(Cell::new(0));
+        let base = PersistentList::new()
+            .push_front(Counted(1, drops.clone()))
+            .push_front(Counted(0, drops.clone()));
+        let longer = base.push_front(Counted(-1, drops.clone()));
+        let tail = base.tail();
+
+        drop(base);
+        assert_eq!(drops.get(), 0);
+        drop(longer);
+        // Only the cells nothing else holds go.
+        assert_eq!(drops.get(), 2);
+        assert_eq!(tail.front().map(|key| key.0), Some(1));
+        drop(tail);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn dropping_a_long_chain_does_not_overflow_the_stack() {
+        let mut list = PersistentList::new();
+        for key in 0..1_000_000 {
+            list = list.push_front(key);
+        }
+        assert!(list.contains(&0) && !list.contains(&-1));
+        drop(list);
+    }
+}
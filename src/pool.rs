@@ -0,0 +1,171 @@
+use alloc::boxed::Box;
+use core::{
+    marker::PhantomData,
+    mem,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use crate::{LinkedList, Node};
+
+// Released slots keep both their node and their value, so an acquire from a
+// non-empty pool neither allocates nor constructs; resetting the value is
+// left to the caller.
+pub struct Pool<T> {
+    pub(crate) free: LinkedList<T>,
+}
+
+pub struct Slot<T> {
+    node: NonNull<Node<T>>,
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+unsafe impl<T: Send> Send for Slot<T> {}
+
+unsafe impl<T: Sync> Sync for Slot<T> {}
+
+impl<T> Slot<T> {
+    pub fn new(key: T) -> Slot<T> {
+        Slot {
+            node: Node::alloc(Node::new(key)),
+            marker: PhantomData,
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        let node = self.node;
+        mem::forget(self);
+        unsafe { Node::free(node) }
+    }
+}
+
+impl<T> Deref for Slot<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &self.node.as_ref().key }
+    }
+}
+
+impl<T> DerefMut for Slot<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut self.node.as_mut().key }
+    }
+}
+
+impl<T> Drop for Slot<T> {
+    fn drop(&mut self) {
+        drop(unsafe { Node::free(self.node) });
+    }
+}
+
+impl<T> Pool<T> {
+    pub const fn new() -> Pool<T> {
+        Pool {
+            free: LinkedList::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.free.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.free.is_empty()
+    }
+
+    pub fn acquire(&mut self) -> Option<Slot<T>> {
+        let node = self.free.pop_front_node()?;
+
+        Some(Slot {
+            node,
+            marker: PhantomData,
+        })
+    }
+
+    pub fn acquire_with<F>(&mut self, f: F) -> Slot<T>
+    where
+        F: FnOnce() -> T,
+    {
+        self.acquire().unwrap_or_else(|| Slot::new(f()))
+    }
+
+    pub fn release(&mut self, slot: Slot<T>) {
+        let node = slot.node;
+        mem::forget(slot);
+        unsafe { self.free.push_back_node(node) };
+    }
+
+    pub fn into_inner(self) -> LinkedList<T> {
+        self.free
+    }
+}
+
+impl<T> Default for Pool<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<LinkedList<T>> for Pool<T> {
+    fn from(free: LinkedList<T>) -> Self {
+        Pool { free }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::*;
+
+    struct Counted(i32, Rc<Cell<usize>>);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    #[test]
+    fn released_slots_come_back_with_node_and_value() {
+        let mut pool = Pool::new();
+        let mut slot = pool.acquire_with(|| vec![0; 4]);
+        slot.push(1);
+        let address: *const Vec<i32> = &*slot;
+
+        pool.release(slot);
+        assert_eq!(pool.len(), 1);
+
+        let slot = pool.acquire_with(|| unreachable!("the pool has a free slot"));
+        assert_eq!(&*slot as *const Vec<i32>, address);
+        assert_eq!(*slot, [0, 0, 0, 0, 1]);
+        assert!(pool.is_empty() && pool.acquire().is_none());
+    }
+
+    #[test]
+    fn slots_are_handed_out_in_release_order() {
+        let mut pool = Pool::from(LinkedList::from([1, 2]));
+        let first = pool.acquire().unwrap();
+        pool.release(Slot::new(3));
+        pool.release(first);
+
+        let keys: Vec<_> = (0..3).map(|_| *pool.acquire().unwrap()).collect();
+        assert_eq!(keys, [2, 3, 1]);
+    }
+
+    #[test]
+    fn every_value_is_dropped_once() {
+        let drops = Rc::new(Cell::new(0));
+        let mut pool = Pool::new();
+        for key in 0..3 {
+            pool.release(Slot::new(Counted(key, drops.clone())));
+        }
+
+        drop(pool.acquire());
+        assert_eq!(drops.get(), 1);
+        assert_eq!(pool.acquire().unwrap().into_inner().0, 1);
+        assert_eq!(drops.get(), 2);
+        drop(pool);
+        assert_eq!(drops.get(), 3);
+    }
+}
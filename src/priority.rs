@@ -0,0 +1,120 @@
+use core::ops::Deref;
+
+use crate::LinkedList;
+
+// Kept in ascending order. An element goes after every element equal to it,
+// so equal elements leave through `pop_min` in insertion order.
+pub struct PriorityList<T> {
+    list: LinkedList<T>,
+}
+
+impl<T: Ord> PriorityList<T> {
+    pub const fn new() -> PriorityList<T> {
+        PriorityList {
+            list: LinkedList::new(),
+        }
+    }
+
+    pub fn into_inner(self) -> LinkedList<T> {
+        self.list
+    }
+
+    // One walk: the new node goes right after the last one not greater than
+    // it.
+    pub fn insert_sorted(&mut self, key: T) {
+        let mut prev = None;
+        let mut node = self.list.head;
+        while let Some(curr) = node {
+            let curr = unsafe { curr.as_ref() };
+            if curr.key > key {
+                break;
+            }
+            prev = node;
+            node = curr.next;
+        }
+
+        match prev {
+            Some(prev) => unsafe {
+                self.list.push_after(prev, key);
+            },
+            None => self.list.push_front(key),
+        }
+    }
+
+    pub fn peek_min(&self) -> Option<&T> {
+        self.list.iter().next()
+    }
+
+    pub fn peek_max(&self) -> Option<&T> {
+        self.list.iter().next_back()
+    }
+
+    pub fn pop_min(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    pub fn pop_max(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> Deref for PriorityList<T> {
+    type Target = LinkedList<T>;
+    fn deref(&self) -> &LinkedList<T> {
+        &self.list
+    }
+}
+
+impl<T: Ord> Default for PriorityList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> Extend<T> for PriorityList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|key| self.insert_sorted(key));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_keys_keep_insertion_order() {
+        let mut list = PriorityList::new();
+        for (key, tag) in [(2, 'a'), (1, 'b'), (3, 'c'), (2, 'd'), (1, 'e'), (3, 'f')] {
+            list.insert_sorted(Tagged(key, tag));
+        }
+
+        let tags: Vec<char> = list.iter().map(|Tagged(_, tag)| *tag).collect();
+        assert_eq!(tags, ['b', 'e', 'a', 'd', 'c', 'f']);
+        assert_eq!(list.pop_min().map(|Tagged(_, tag)| tag), Some('b'));
+        assert_eq!(list.pop_max().map(|Tagged(_, tag)| tag), Some('f'));
+        list.debug_validate();
+    }
+
+    // Ordered by the key alone.
+    struct Tagged(i32, char);
+
+    impl PartialEq for Tagged {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Tagged {}
+
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use core::{marker::PhantomData, ptr};
+
+use crate::concurrent::sync::{fence, yield_now, AtomicPtr, AtomicUsize, Mutex, Ordering};
+
+struct Node<T> {
+    key: T,
+    next: AtomicPtr<Node<T>>,
+}
+
+impl<T> Node<T> {
+    fn new(key: T, next: *mut Node<T>) -> *mut Node<T> {
+        Box::into_raw(Box::new(Node {
+            key,
+            next: AtomicPtr::new(next),
+        }))
+    }
+}
+
+struct Writer<T> {
+    tail: *mut Node<T>,
+}
+
+pub struct RcuList<T> {
+    head: AtomicPtr<Node<T>>,
+    len: AtomicUsize,
+    writer: Mutex<Writer<T>>,
+    epoch: AtomicUsize,
+    readers: [AtomicUsize; 2],
+}
+
+unsafe impl<T: Send> Send for RcuList<T> {}
+
+unsafe impl<T: Send + Sync> Sync for RcuList<T> {}
+
+pub struct ReadGuard<'a, T> {
+    list: &'a RcuList<T>,
+    slot: usize,
+}
+
+pub struct Iter<'a, T> {
+    node: *const Node<T>,
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = unsafe { self.node.as_ref() }?;
+
+        self.node = node.next.load(Ordering::Acquire);
+
+        Some(&node.key)
+    }
+}
+
+impl<T> RcuList<T> {
+    pub fn new() -> RcuList<T> {
+        RcuList {
+            head: AtomicPtr::new(ptr::null_mut()),
+            len: AtomicUsize::new(0),
+            writer: Mutex::new(Writer {
+                tail: ptr::null_mut(),
+            }),
+            epoch: AtomicUsize::new(0),
+            readers: [AtomicUsize::new(0), AtomicUsize::new(0)],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Acquire)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn read(&self) -> ReadGuard<'_, T> {
+        let slot = self.epoch.load(Ordering::SeqCst) & 1;
+        self.readers[slot].fetch_add(1, Ordering::SeqCst);
+        // Pairs with the fence in `synchronize`: either the writer sees this
+        // reader, or this reader never sees the node being unlinked.
+        fence(Ordering::SeqCst);
+
+        ReadGuard { list: self, slot }
+    }
+
+    pub fn push_front(&self, key: T) {
+        let mut writer = self.writer.lock().unwrap();
+
+        let head = self.head.load(Ordering::Relaxed);
+        let node = Node::new(key, head);
+
+        self.head.store(node, Ordering::Release);
+        if writer.tail.is_null() {
+            writer.tail = node;
+        }
+        self.len.fetch_add(1, Ordering::Release);
+    }
+
+    pub fn push_back(&self, key: T) {
+        let mut writer = self.writer.lock().unwrap();
+
+        let node = Node::new(key, ptr::null_mut());
+
+        match unsafe { writer.tail.as_ref() } {
+            Some(tail) => tail.next.store(node, Ordering::Release),
+            None => self.head.store(node, Ordering::Release),
+        }
+        writer.tail = node;
+        self.len.fetch_add(1, Ordering::Release);
+    }
+
+    // Removing methods wait for every reader that might still observe the
+    // unlinked node, so they must not be called while the calling thread
+    // holds a `ReadGuard` on the same list.
+    pub fn pop_front(&self) -> Option<T> {
+        let mut writer = self.writer.lock().unwrap();
+
+        let head = self.head.load(Ordering::Relaxed);
+        let node = unsafe { head.as_ref() }?;
+
+        let next = node.next.load(Ordering::Relaxed);
+        self.head.store(next, Ordering::Release);
+        if next.is_null() {
+            writer.tail = ptr::null_mut();
+        }
+        self.len.fetch_sub(1, Ordering::Release);
+
+        self.synchronize();
+
+        Some(unsafe { Box::from_raw(head) }.key)
+    }
+
+    pub fn remove(&self, key: &T) -> Option<T>
+    where
+        T: PartialEq<T>,
+    {
+        let mut writer = self.writer.lock().unwrap();
+
+        let mut prev: *mut Node<T> = ptr::null_mut();
+        let mut curr = self.head.load(Ordering::Relaxed);
+
+        while let Some(node) = unsafe { curr.as_ref() } {
+            let next = node.next.load(Ordering::Relaxed);
+
+            if node.key == *key {
+                match unsafe { prev.as_ref() } {
+                    Some(prev) => prev.next.store(next, Ordering::Release),
+                    None => self.head.store(next, Ordering::Release),
+                }
+                if next.is_null() {
+                    writer.tail = prev;
+                }
+                self.len.fetch_sub(1, Ordering::Release);
+
+                self.synchronize();
+
+                return Some(unsafe { Box::from_raw(curr) }.key);
+            }
+
+            prev = curr;
+            curr = next;
+        }
+
+        None
+    }
+
+    pub fn contains(&self, key: &T) -> bool
+    where
+        T: PartialEq<T>,
+    {
+        self.read().iter().any(|x| *x == *key)
+    }
+
+    // Waits for a grace period: every reader that entered before the caller
+    // unlinked a node has left. Each reader slot is observed empty once after
+    // the unlink, flipping the epoch first so new readers go to the other slot.
+    fn synchronize(&self) {
+        fence(Ordering::SeqCst);
+
+        for _ in 0..2 {
+            let slot = self.epoch.fetch_add(1, Ordering::SeqCst) & 1;
+            while self.readers[slot].load(Ordering::SeqCst) != 0 {
+                yield_now();
+            }
+        }
+    }
+}
+
+impl<T> ReadGuard<'_, T> {
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            node: self.list.head.load(Ordering::Acquire),
+            marker: PhantomData,
+        }
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.iter().next()
+    }
+}
+
+impl<T> Drop for ReadGuard<'_, T> {
+    fn drop(&mut self) {
+        self.list.readers[self.slot].fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl<T> Default for RcuList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for RcuList<T> {
+    fn drop(&mut self) {
+        let mut curr = self.head.load(Ordering::Relaxed);
+
+        while !curr.is_null() {
+            let node = unsafe { Box::from_raw(curr) };
+            curr = node.next.load(Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+        thread,
+    };
+
+    use super::*;
+
+    const READERS: usize = 3;
+    const KEYS: usize = 20_000;
+    const WINDOW: usize = 32;
+
+    // An element that marks itself dropped in a table outside the list, so a
+    // reader can tell whether a node it sees has been freed.
+    struct Probe<'a> {
+        key: usize,
+        dropped: &'a [AtomicBool],
+    }
+
+    impl Drop for Probe<'_> {
+        fn drop(&mut self) {
+            assert!(!self.dropped[self.key].swap(true, Ordering::SeqCst));
+        }
+    }
+
+    #[test]
+    fn readers_see_consistent_snapshots_of_live_nodes() {
+        let dropped: Vec<_> = (0..KEYS).map(|_| AtomicBool::new(false)).collect();
+        let done = AtomicBool::new(false);
+        let list = RcuList::<Probe>::new();
+
+        thread::scope(|scope| {
+            for _ in 0..READERS {
+                scope.spawn(|| {
+                    while !done.load(Ordering::Acquire) {
+                        let guard = list.read();
+                        let keys: Vec<_> = guard.iter().map(|probe| probe.key).collect();
+
+                        // The writer only appends and pops the front, so any
+                        // snapshot is a run of consecutive keys.
+                        assert!(keys.windows(2).all(|pair| pair[0] + 1 == pair[1]));
+                        // Nothing seen is freed before the guard goes.
+                        for &key in &keys {
+                            assert!(!dropped[key].load(Ordering::SeqCst));
+                        }
+                        drop(guard);
+                        thread::yield_now();
+                    }
+                });
+            }
+
+            for key in 0..KEYS {
+                list.push_back(Probe {
+                    key,
+                    dropped: &dropped,
+                });
+                if key >= WINDOW {
+                    drop(list.pop_front());
+                }
+                // Lets the readers in between updates even on a single core.
+                thread::yield_now();
+            }
+            done.store(true, Ordering::Release);
+        });
+
+        let popped = dropped
+            .iter()
+            .filter(|dropped| dropped.load(Ordering::SeqCst))
+            .count();
+        assert_eq!(popped, KEYS - WINDOW);
+        assert_eq!(list.len(), WINDOW);
+
+        drop(list);
+        assert!(dropped.iter().all(|dropped| dropped.load(Ordering::SeqCst)));
+    }
+
+    #[test]
+    fn remove_waits_for_readers_and_relinks() {
+        let list = RcuList::new();
+        for key in 0..4 {
+            list.push_back(key);
+        }
+
+        let removed = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            let guard = list.read();
+            scope.spawn(|| {
+                assert_eq!(list.remove(&3), Some(3));
+                removed.store(1, Ordering::SeqCst);
+            });
+
+            // The remover cannot free the node while this guard is held.
+            thread::yield_now();
+            assert_eq!(removed.load(Ordering::SeqCst), 0);
+            assert!(guard.iter().eq(&[0, 1, 2, 3]) || guard.iter().eq(&[0, 1, 2]));
+        });
+
+        assert_eq!(list.remove(&0), Some(0));
+        assert_eq!(list.remove(&7), None);
+        list.push_back(4);
+        list.push_front(5);
+        assert!(list.read().iter().eq(&[5, 1, 2, 4]));
+        assert_eq!(list.len(), 4);
+        assert!(list.contains(&4) && !list.contains(&3));
+    }
+}
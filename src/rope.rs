@@ -0,0 +1,344 @@
+use alloc::{borrow::ToOwned, string::String};
+use core::{fmt, iter::FusedIterator, ops::Range, ptr::NonNull};
+
+use crate::{self as list, LinkedList, Node};
+
+// Chunks are kept between `CHUNK / 2` and `2 * CHUNK` chars where possible.
+// Each one caches its char count, so reaching an offset means skipping whole
+// chunks and then scanning within a single chunk.
+const CHUNK: usize = 64;
+
+struct Chunk {
+    text: String,
+    chars: usize,
+}
+
+impl Chunk {
+    fn new(text: String) -> Chunk {
+        let chars = text.chars().count();
+        Chunk { text, chars }
+    }
+
+    fn byte_offset(&self, at: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(at)
+            .map_or(self.text.len(), |(offset, _)| offset)
+    }
+}
+
+pub struct Rope {
+    chunks: LinkedList<Chunk>,
+    chars: usize,
+}
+
+pub struct Chunks<'a> {
+    inner: list::Iter<'a, Chunk>,
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|chunk| chunk.text.as_str())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Chunks<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|chunk| chunk.text.as_str())
+    }
+}
+
+impl ExactSizeIterator for Chunks<'_> {}
+
+impl FusedIterator for Chunks<'_> {}
+
+impl Rope {
+    pub const fn new() -> Rope {
+        Rope {
+            chunks: LinkedList::new(),
+            chars: 0,
+        }
+    }
+
+    pub fn len_chars(&self) -> usize {
+        self.chars
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chars == 0
+    }
+
+    pub fn chunks(&self) -> Chunks<'_> {
+        Chunks {
+            inner: self.chunks.iter(),
+        }
+    }
+
+    // Finds the chunk holding char `at` and the offset of `at` inside it. An
+    // offset at a boundary resolves to the end of the earlier chunk.
+    fn locate(&self, at: usize) -> Option<(NonNull<Node<Chunk>>, usize)> {
+        let mut start = 0;
+        let mut node = self.chunks.head;
+        while let Some(curr) = node {
+            let chars = unsafe { curr.as_ref() }.key.chars;
+            if at <= start + chars {
+                return Some((curr, at - start));
+            }
+            start += chars;
+            node = unsafe { curr.as_ref() }.next;
+        }
+
+        None
+    }
+
+    pub fn insert(&mut self, at: usize, text: &str) {
+        assert!(
+            at <= self.chars,
+            "Index out of bound: len is `{}` but index is `{}`",
+            self.chars,
+            at
+        );
+
+        if text.is_empty() {
+            return;
+        }
+
+        let node = match self.locate(at) {
+            Some((node, offset)) => {
+                let chunk = &mut unsafe { &mut *node.as_ptr() }.key;
+                let byte = chunk.byte_offset(offset);
+                let added = Chunk::new(text.to_owned());
+
+                chunk.text.insert_str(byte, &added.text);
+                chunk.chars += added.chars;
+                self.chars += added.chars;
+                node
+            }
+            None => {
+                let added = Chunk::new(text.to_owned());
+                self.chars += added.chars;
+                self.chunks.push_back(added);
+                unsafe { self.chunks.tail.unwrap_unchecked() }
+            }
+        };
+
+        self.split(node);
+    }
+
+    // Cuts an oversized chunk into `CHUNK`-char pieces linked in after it.
+    fn split(&mut self, mut node: NonNull<Node<Chunk>>) {
+        let chunk = &mut unsafe { node.as_mut() }.key;
+        if chunk.chars <= 2 * CHUNK {
+            return;
+        }
+
+        let rest = chunk.text.split_off(chunk.byte_offset(CHUNK));
+        chunk.chars = CHUNK;
+
+        let mut piece = String::new();
+        let mut chars = 0;
+        for c in rest.chars() {
+            piece.push(c);
+            chars += 1;
+            if chars == CHUNK {
+                let text = core::mem::take(&mut piece);
+                node = unsafe { self.chunks.push_after(node, Chunk { text, chars }) };
+                chars = 0;
+            }
+        }
+        if chars > 0 {
+            unsafe { self.chunks.push_after(node, Chunk { text: piece, chars }) };
+        }
+    }
+
+    pub fn remove(&mut self, range: Range<usize>) {
+        assert!(
+            range.start <= range.end && range.end <= self.chars,
+            "Range out of bound: len is `{}` but range is `{:?}`",
+            self.chars,
+            range
+        );
+
+        let Some((mut node, mut offset)) = self.locate(range.start) else {
+            return;
+        };
+        let mut remaining = range.end - range.start;
+        self.chars -= remaining;
+
+        while remaining > 0 {
+            let next = unsafe { node.as_ref() }.next;
+            let chunk = &mut unsafe { node.as_mut() }.key;
+            let count = remaining.min(chunk.chars - offset);
+            let start = chunk.byte_offset(offset);
+            let end = chunk.byte_offset(offset + count);
+
+            chunk.text.replace_range(start..end, "");
+            chunk.chars -= count;
+            remaining -= count;
+
+            if chunk.chars == 0 {
+                unsafe { self.chunks.unlink(node) };
+            }
+            match next {
+                Some(next) => node = next,
+                None => break,
+            }
+            offset = 0;
+        }
+
+        if let Some((node, _)) = self.locate(range.start) {
+            self.merge(node);
+        }
+    }
+
+    // Folds the following chunk into an undersized one when the two fit.
+    fn merge(&mut self, mut node: NonNull<Node<Chunk>>) {
+        let Some(next) = unsafe { node.as_ref() }.next else {
+            return;
+        };
+
+        let chunk = &unsafe { node.as_ref() }.key;
+        let other = &unsafe { next.as_ref() }.key;
+        if chunk.chars >= CHUNK / 2 && other.chars >= CHUNK / 2 {
+            return;
+        }
+        if chunk.chars + other.chars > 2 * CHUNK {
+            return;
+        }
+
+        let other = unsafe { self.chunks.unlink(next) };
+        let chunk = &mut unsafe { node.as_mut() }.key;
+        chunk.text.push_str(&other.text);
+        chunk.chars += other.chars;
+    }
+}
+
+impl Default for Rope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&str> for Rope {
+    fn from(text: &str) -> Self {
+        let mut rope = Rope::new();
+        rope.insert(0, text);
+        rope
+    }
+}
+
+impl fmt::Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chunks().try_for_each(|chunk| f.write_str(chunk))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Rng;
+
+    fn char_range(text: &str, range: Range<usize>) -> Range<usize> {
+        let byte = |at| {
+            text.char_indices()
+                .nth(at)
+                .map_or(text.len(), |(byte, _)| byte)
+        };
+        byte(range.start)..byte(range.end)
+    }
+
+    // Every chunk is non-empty, no larger than `2 * CHUNK`, and caches its
+    // real char count.
+    fn check(rope: &Rope, model: &str) {
+        assert_eq!(rope.to_string(), model);
+        assert_eq!(rope.len_chars(), model.chars().count());
+        for chunk in rope.chunks.iter() {
+            assert_eq!(chunk.chars, chunk.text.chars().count());
+            assert!(chunk.chars > 0 && chunk.chars <= 2 * CHUNK);
+        }
+        rope.chunks.debug_validate();
+    }
+
+    #[test]
+    fn long_insert_is_cut_into_chunks() {
+        let text = "日本語のテキスト→é😀".repeat(40);
+        let rope = Rope::from(text.as_str());
+        check(&rope, &text);
+        assert!(rope.chunks().len() > 1);
+        assert!(rope.chunks().all(|chunk| chunk.chars().count() <= CHUNK));
+    }
+
+    #[test]
+    fn removal_across_chunks_merges_the_ends() {
+        let text = "ñ😀".repeat(200);
+        let mut rope = Rope::from(text.as_str());
+        let mut model = text.clone();
+
+        rope.remove(CHUNK - 3..3 * CHUNK + 3);
+        model.replace_range(char_range(&model, CHUNK - 3..3 * CHUNK + 3), "");
+        check(&rope, &model);
+
+        rope.remove(0..rope.len_chars());
+        check(&rope, "");
+        assert_eq!(rope.chunks().len(), 0);
+    }
+
+    #[test]
+    fn edits_at_chunk_boundaries() {
+        let mut rope = Rope::from("α".repeat(3 * CHUNK).as_str());
+        let mut model = rope.to_string();
+
+        for at in [CHUNK, 2 * CHUNK, 0, rope.len_chars()] {
+            rope.insert(at, "β🍣");
+            let byte = char_range(&model, at..at).start;
+            model.insert_str(byte, "β🍣");
+            check(&rope, &model);
+        }
+
+        rope.remove(CHUNK..CHUNK + 2);
+        model.replace_range(char_range(&model, CHUNK..CHUNK + 2), "");
+        check(&rope, &model);
+    }
+
+    #[test]
+    fn random_edits_match_a_string() {
+        let mut rng = Rng::new(0x9e37_79b9);
+        let pieces = [
+            "a",
+            "é",
+            "日本",
+            "😀x",
+            "ŝtŕîñĝ",
+            "→→→→→→→→→→→→→→→→→→→→→→→→→→→→→→→→→→→",
+        ];
+
+        let mut rope = Rope::new();
+        let mut model = String::new();
+        for _ in 0..500 {
+            let len = model.chars().count();
+            if len > 0 && rng.index(3) == 0 {
+                let start = rng.index(len + 1);
+                let end = start + rng.index(len - start + 1).min(3 * CHUNK);
+                rope.remove(start..end);
+                model.replace_range(char_range(&model, start..end), "");
+            } else {
+                let at = rng.index(len + 1);
+                let text = pieces[rng.index(pieces.len())].repeat(1 + rng.index(8));
+                rope.insert(at, &text);
+                model.insert_str(char_range(&model, at..at).start, &text);
+            }
+            check(&rope, &model);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Range out of bound")]
+    fn removal_past_the_end_panics() {
+        Rope::from("ab").remove(1..3);
+    }
+}
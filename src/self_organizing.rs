@@ -0,0 +1,86 @@
+use core::ops::Deref;
+
+use crate::LinkedList;
+
+// Move-to-front: every successful lookup relinks the element it found to the
+// head, so elements that are looked up often stay near the front.
+pub struct SelfOrganizingList<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> SelfOrganizingList<T> {
+    pub const fn new() -> SelfOrganizingList<T> {
+        SelfOrganizingList {
+            list: LinkedList::new(),
+        }
+    }
+
+    pub fn into_inner(self) -> LinkedList<T> {
+        self.list
+    }
+
+    pub fn push_front(&mut self, key: T) {
+        self.list.push_front(key)
+    }
+
+    pub fn push_back(&mut self, key: T) {
+        self.list.push_back(key)
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+
+    pub fn find_mut<P>(&mut self, mut predicate: P) -> Option<&mut T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut node = self.list.head;
+        while let Some(curr) = node {
+            if predicate(&unsafe { curr.as_ref() }.key) {
+                unsafe { self.list.move_to_front(curr) };
+                return Some(&mut unsafe { &mut *curr.as_ptr() }.key);
+            }
+            node = unsafe { curr.as_ref() }.next;
+        }
+
+        None
+    }
+
+    pub fn find<P>(&mut self, predicate: P) -> Option<&T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.find_mut(predicate).map(|key| &*key)
+    }
+
+    pub fn contains(&mut self, key: &T) -> bool
+    where
+        T: PartialEq<T>,
+    {
+        self.find(|other| *other == *key).is_some()
+    }
+}
+
+impl<T> Deref for SelfOrganizingList<T> {
+    type Target = LinkedList<T>;
+    fn deref(&self) -> &LinkedList<T> {
+        &self.list
+    }
+}
+
+impl<T> Default for SelfOrganizingList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<LinkedList<T>> for SelfOrganizingList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        SelfOrganizingList { list }
+    }
+}
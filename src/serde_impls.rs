@@ -0,0 +1,67 @@
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::LinkedList;
+
+// A list is a sequence, front to back; `serde_map` covers lists of pairs
+// that should look like maps instead.
+impl<T: Serialize> Serialize for LinkedList<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for key in self.iter() {
+            seq.serialize_element(key)?;
+        }
+
+        seq.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for LinkedList<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(SeqVisitor(PhantomData))
+    }
+}
+
+struct SeqVisitor<T>(PhantomData<fn() -> T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for SeqVisitor<T> {
+    type Value = LinkedList<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut list = LinkedList::new();
+        while let Some(key) = access.next_element()? {
+            list.push_back(key);
+        }
+
+        Ok(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LinkedList;
+
+    #[test]
+    fn round_trips_as_a_sequence() {
+        let list: LinkedList<Vec<u8>> = [vec![1], vec![], vec![2, 3]].into();
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, "[[1],[],[2,3]]");
+        assert_eq!(
+            serde_json::from_str::<LinkedList<Vec<u8>>>(&json).unwrap(),
+            list
+        );
+        assert!(serde_json::from_str::<LinkedList<u8>>("{}").is_err());
+    }
+}
@@ -0,0 +1,91 @@
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::LinkedList;
+
+// Serializes a `LinkedList<(K, V)>` as a map rather than a sequence of
+// pairs, for use as `#[serde(with = "linked_list::serde_map")]`. Entries keep
+// list order both ways, and repeated keys are all kept on the way back in.
+pub fn serialize<K, V, S>(list: &LinkedList<(K, V)>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Serialize,
+    V: Serialize,
+    S: Serializer,
+{
+    let mut map = serializer.serialize_map(Some(list.len()))?;
+    for (key, value) in list.iter() {
+        map.serialize_entry(key, value)?;
+    }
+
+    map.end()
+}
+
+pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<LinkedList<(K, V)>, D::Error>
+where
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(MapVisitor(PhantomData))
+}
+
+struct MapVisitor<K, V>(PhantomData<fn() -> (K, V)>);
+
+impl<'de, K, V> Visitor<'de> for MapVisitor<K, V>
+where
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    type Value = LinkedList<(K, V)>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut list = LinkedList::new();
+        while let Some(entry) = access.next_entry()? {
+            list.push_back(entry);
+        }
+
+        Ok(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LinkedList;
+
+    fn to_json(list: &LinkedList<(String, u32)>) -> String {
+        let mut json = Vec::new();
+        super::serialize(list, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        String::from_utf8(json).unwrap()
+    }
+
+    fn from_json(json: &str) -> serde_json::Result<LinkedList<(String, u32)>> {
+        super::deserialize(&mut serde_json::Deserializer::from_str(json))
+    }
+
+    #[test]
+    fn round_trips_in_list_order() {
+        let list: LinkedList<_> = [("z".to_string(), 1), ("a".to_string(), 2)].into();
+        let json = to_json(&list);
+        assert_eq!(json, r#"{"z":1,"a":2}"#);
+        assert_eq!(from_json(&json).unwrap(), list);
+    }
+
+    #[test]
+    fn repeated_keys_are_all_kept() {
+        let list = from_json(r#"{"b":1,"b":2}"#).unwrap();
+        assert!(list.eq_by([("b", 1), ("b", 2)], |(k, v), (k2, v2)| k == k2 && *v == v2));
+        assert!(from_json("[1]").is_err());
+    }
+}
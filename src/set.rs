@@ -0,0 +1,38 @@
+use std::{collections::HashSet, hash::Hash};
+
+use crate::LinkedList;
+
+// Set-style operations: each value appears at most once in the result, in
+// the order of its first occurrence in `self` (and then in `other`).
+impl<T> LinkedList<T>
+where
+    T: Hash + Eq,
+{
+    pub fn difference<'a>(&'a self, other: &'a LinkedList<T>) -> LinkedList<&'a T> {
+        let mut seen: HashSet<&T> = other.iter().collect();
+
+        let mut difference = LinkedList::new();
+        difference.extend(self.iter().filter(|&key| seen.insert(key)));
+        difference
+    }
+
+    pub fn intersection<'a>(&'a self, other: &'a LinkedList<T>) -> LinkedList<&'a T> {
+        let mut unseen: HashSet<&T> = other.iter().collect();
+
+        let mut intersection = LinkedList::new();
+        intersection.extend(self.iter().filter(|&key| unseen.remove(key)));
+        intersection
+    }
+
+    pub fn union<'a>(&'a self, other: &'a LinkedList<T>) -> LinkedList<&'a T> {
+        let mut seen = HashSet::new();
+
+        let mut union = LinkedList::new();
+        union.extend(
+            self.iter()
+                .chain(other.iter())
+                .filter(|&key| seen.insert(key)),
+        );
+        union
+    }
+}
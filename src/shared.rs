@@ -0,0 +1,128 @@
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::LinkedList;
+
+pub struct SharedLinkedList<T> {
+    inner: Arc<RwLock<LinkedList<T>>>,
+}
+
+impl<T> SharedLinkedList<T> {
+    pub fn new() -> SharedLinkedList<T> {
+        SharedLinkedList::from(LinkedList::new())
+    }
+
+    pub fn read(&self) -> RwLockReadGuard<'_, LinkedList<T>> {
+        self.inner.read().unwrap()
+    }
+
+    pub fn write(&self) -> RwLockWriteGuard<'_, LinkedList<T>> {
+        self.inner.write().unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+
+    pub fn push_front(&self, key: T) {
+        self.write().push_front(key)
+    }
+
+    pub fn push_back(&self, key: T) {
+        self.write().push_back(key)
+    }
+
+    pub fn pop_front(&self) -> Option<T> {
+        self.write().pop_front()
+    }
+
+    pub fn pop_back(&self) -> Option<T> {
+        self.write().pop_back()
+    }
+
+    pub fn contains(&self, key: &T) -> bool
+    where
+        T: PartialEq<T>,
+    {
+        self.read().contains(key)
+    }
+
+    pub fn for_each<F>(&self, f: F)
+    where
+        F: FnMut(&T),
+    {
+        self.read().iter().for_each(f)
+    }
+}
+
+impl<T> Clone for SharedLinkedList<T> {
+    fn clone(&self) -> Self {
+        SharedLinkedList {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T> Default for SharedLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<LinkedList<T>> for SharedLinkedList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        SharedLinkedList {
+            inner: Arc::new(RwLock::new(list)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn clones_share_one_list() {
+        let shared = SharedLinkedList::from(LinkedList::from([1, 2]));
+        let other = shared.clone();
+
+        other.push_front(0);
+        shared.push_back(3);
+        assert_eq!(other.len(), 4);
+        assert!(shared.read().iter().eq(&[0, 1, 2, 3]));
+
+        assert_eq!(other.pop_back(), Some(3));
+        assert_eq!(shared.pop_front(), Some(0));
+        assert!(shared.contains(&2) && !other.contains(&0));
+
+        let mut seen = Vec::new();
+        other.for_each(|key| seen.push(*key));
+        assert_eq!(seen, [1, 2]);
+    }
+
+    #[test]
+    fn pushes_from_many_threads_all_land() {
+        let shared = SharedLinkedList::new();
+
+        thread::scope(|scope| {
+            for thread in 0..4 {
+                let shared = shared.clone();
+                scope.spawn(move || {
+                    for key in 0..1_000 {
+                        shared.push_back(thread * 1_000 + key);
+                    }
+                });
+            }
+        });
+
+        let mut keys: Vec<_> = shared.write().take().into_iter().collect();
+        keys.sort_unstable();
+        assert!(keys.into_iter().eq(0..4_000));
+        assert!(shared.is_empty());
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(feature = "malloc_size_of")]
+use malloc_size_of::{MallocShallowSizeOf, MallocSizeOf, MallocSizeOfOps};
+
+#[cfg(feature = "deepsize")]
+use crate::Node;
+use crate::{LinkedList, Pool};
+
+// Every element lives in its own node, so both measurements charge each
+// element for the node around it; a pool is measured as its free list.
+#[cfg(feature = "deepsize")]
+impl<T: deepsize::DeepSizeOf> deepsize::DeepSizeOf for LinkedList<T> {
+    fn deep_size_of_children(&self, context: &mut deepsize::Context) -> usize {
+        self.iter().fold(0, |sum, key| {
+            sum + std::mem::size_of::<Node<T>>() + key.deep_size_of_children(context)
+        })
+    }
+}
+
+#[cfg(feature = "deepsize")]
+impl<T: deepsize::DeepSizeOf> deepsize::DeepSizeOf for Pool<T> {
+    fn deep_size_of_children(&self, context: &mut deepsize::Context) -> usize {
+        self.free.deep_size_of_children(context)
+    }
+}
+
+#[cfg(feature = "malloc_size_of")]
+impl<T> MallocShallowSizeOf for LinkedList<T> {
+    fn shallow_size_of(&self, ops: &mut MallocSizeOfOps) -> usize {
+        let mut size = 0;
+        let mut node = self.head;
+        while let Some(curr) = node {
+            size += unsafe { ops.malloc_size_of(curr.as_ptr()) };
+            node = unsafe { curr.as_ref() }.next;
+        }
+
+        size
+    }
+}
+
+#[cfg(feature = "malloc_size_of")]
+impl<T: MallocSizeOf> MallocSizeOf for LinkedList<T> {
+    fn size_of(&self, ops: &mut MallocSizeOfOps) -> usize {
+        let mut size = self.shallow_size_of(ops);
+        for key in self.iter() {
+            size += key.size_of(ops);
+        }
+
+        size
+    }
+}
+
+#[cfg(feature = "malloc_size_of")]
+impl<T> MallocShallowSizeOf for Pool<T> {
+    fn shallow_size_of(&self, ops: &mut MallocSizeOfOps) -> usize {
+        self.free.shallow_size_of(ops)
+    }
+}
+
+#[cfg(feature = "malloc_size_of")]
+impl<T: MallocSizeOf> MallocSizeOf for Pool<T> {
+    fn size_of(&self, ops: &mut MallocSizeOfOps) -> usize {
+        self.free.size_of(ops)
+    }
+}
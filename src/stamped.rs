@@ -0,0 +1,233 @@
+use std::{collections::HashMap, error::Error, fmt, iter::FusedIterator, ptr::NonNull};
+
+use crate::{self as list, LinkedList, Node};
+
+// A handle to one element of a `StampedList`. Unlike a `NodeRef`, it stays
+// good across changes to the rest of the list, and only goes stale once its
+// own element is removed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StampedRef {
+    id: usize,
+    stamp: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StaleHandle;
+
+impl fmt::Display for StaleHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "handle refers to an element that is no longer in the list"
+        )
+    }
+}
+
+impl Error for StaleHandle {}
+
+// Every element is stored next to the stamp it was given on insertion, and
+// the list keeps a map from each live stamp to its node. A handle is checked
+// by looking its stamp up, so a removed element's node is never touched
+// again; stamps are not reused, so a later element cannot answer for it.
+pub struct StampedList<T> {
+    list: LinkedList<(u64, T)>,
+    nodes: HashMap<u64, NonNull<Node<(u64, T)>>>,
+    next_stamp: u64,
+}
+
+unsafe impl<T: Send> Send for StampedList<T> {}
+
+unsafe impl<T: Sync> Sync for StampedList<T> {}
+
+pub struct Iter<'a, T> {
+    inner: list::Iter<'a, (u64, T)>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, key)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, key)| key)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> StampedList<T> {
+    pub fn new() -> StampedList<T> {
+        StampedList {
+            list: LinkedList::new(),
+            nodes: HashMap::new(),
+            next_stamp: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.list.iter(),
+        }
+    }
+
+    fn stamp(&mut self) -> u64 {
+        let stamp = self.next_stamp;
+        self.next_stamp += 1;
+        stamp
+    }
+
+    pub fn push_front(&mut self, key: T) -> StampedRef {
+        let stamp = self.stamp();
+        self.list.push_front((stamp, key));
+        let node = unsafe { self.list.head.unwrap_unchecked() };
+        self.nodes.insert(stamp, node);
+
+        StampedRef {
+            id: self.list.id(),
+            stamp,
+        }
+    }
+
+    pub fn push_back(&mut self, key: T) -> StampedRef {
+        let stamp = self.stamp();
+        self.list.push_back((stamp, key));
+        let node = unsafe { self.list.tail.unwrap_unchecked() };
+        self.nodes.insert(stamp, node);
+
+        StampedRef {
+            id: self.list.id(),
+            stamp,
+        }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let (stamp, key) = self.list.pop_front()?;
+        self.nodes.remove(&stamp);
+        Some(key)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let (stamp, key) = self.list.pop_back()?;
+        self.nodes.remove(&stamp);
+        Some(key)
+    }
+
+    fn node(&self, handle: StampedRef) -> Result<NonNull<Node<(u64, T)>>, StaleHandle> {
+        if handle.id != self.list.id() {
+            return Err(StaleHandle);
+        }
+
+        self.nodes.get(&handle.stamp).copied().ok_or(StaleHandle)
+    }
+
+    pub fn contains_handle(&self, handle: StampedRef) -> bool {
+        self.node(handle).is_ok()
+    }
+
+    pub fn get_by_handle(&self, handle: StampedRef) -> Result<&T, StaleHandle> {
+        let node = self.node(handle)?;
+        Ok(&unsafe { &*node.as_ptr() }.key.1)
+    }
+
+    pub fn get_mut_by_handle(&mut self, handle: StampedRef) -> Result<&mut T, StaleHandle> {
+        let node = self.node(handle)?;
+        Ok(&mut unsafe { &mut *node.as_ptr() }.key.1)
+    }
+
+    pub fn remove_by_handle(&mut self, handle: StampedRef) -> Result<T, StaleHandle> {
+        let node = self.node(handle)?;
+        self.nodes.remove(&handle.stamp);
+        Ok(unsafe { self.list.unlink(node) }.1)
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear();
+        self.nodes.clear();
+    }
+}
+
+impl<T> Default for StampedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for StampedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = StampedList::new();
+        for key in iter {
+            list.push_back(key);
+        }
+
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_survive_changes_to_other_elements() {
+        let mut list = StampedList::new();
+        let handles: Vec<_> = (0..6)
+            .map(|key| match key % 2 {
+                0 => list.push_back(key),
+                _ => list.push_front(key),
+            })
+            .collect();
+        assert!(list.iter().eq(&[5, 3, 1, 0, 2, 4]));
+
+        assert_eq!(list.remove_by_handle(handles[3]), Ok(3));
+        assert_eq!(list.pop_front(), Some(5));
+        *list.get_mut_by_handle(handles[4]).unwrap() += 10;
+        assert_eq!(list.get_by_handle(handles[4]), Ok(&14));
+        assert!(list.iter().rev().eq(&[14, 2, 0, 1]));
+    }
+
+    #[test]
+    fn removed_elements_report_stale_handles() {
+        let mut list: StampedList<i32> = (0..3).collect();
+        let front = list.push_front(9);
+        let back = list.push_back(9);
+
+        assert_eq!(list.remove_by_handle(front), Ok(9));
+        assert_eq!(list.remove_by_handle(front), Err(StaleHandle));
+        assert_eq!(list.pop_back(), Some(9));
+        assert_eq!(list.get_by_handle(back), Err(StaleHandle));
+
+        let handle = list.push_back(7);
+        list.clear();
+        assert!(!list.contains_handle(handle));
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn handles_from_another_list_are_stale() {
+        let mut first = StampedList::new();
+        let mut second = StampedList::new();
+        let handle = first.push_back(1);
+        second.push_back(2);
+
+        assert_eq!(second.get_by_handle(handle), Err(StaleHandle));
+        assert_eq!(second.remove_by_handle(handle), Err(StaleHandle));
+        assert_eq!(second.len(), 1);
+    }
+}
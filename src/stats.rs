@@ -0,0 +1,47 @@
+// `traversals` counts the nodes walked by positional operations such as
+// `push_at`, `pop_at` and `get_many_mut`; walks driven by an iterator are
+// left to the caller to measure. A node whose memory comes back out of the
+// node cache, or is recycled by `map` or `clone_from`, counts as a reuse
+// rather than an allocation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub pushes: u64,
+    pub pops: u64,
+    pub traversals: u64,
+    pub allocations: u64,
+    pub reuses: u64,
+}
+
+impl Stats {
+    pub(crate) const fn new() -> Stats {
+        Stats {
+            pushes: 0,
+            pops: 0,
+            traversals: 0,
+            allocations: 0,
+            reuses: 0,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "node-cache"))]
+mod tests {
+    use crate::LinkedList;
+
+    #[test]
+    fn cached_node_counts_as_reuse() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.pop_back();
+        list.reset_stats();
+
+        list.push_back(2);
+        list.push_front(1);
+        list.pop_front();
+        list.push_front(0);
+        let stats = list.stats();
+        assert_eq!(stats.pushes, 3);
+        assert_eq!(stats.reuses + stats.allocations, 3);
+        assert!(stats.reuses >= 2);
+    }
+}
@@ -0,0 +1,326 @@
+use core::{
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+    ptr::NonNull,
+};
+
+use crate::{Iter, IterMut, LinkedList, Node};
+
+// A borrowed window onto a contiguous run of a list, from `range`,
+// `range_mut` or `split_at_mut`. It only holds the first and last node of the
+// run, so taking one costs the walk to its ends and nothing more.
+pub struct SubList<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+pub struct SubListMut<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a mut Node<T>>,
+}
+
+unsafe impl<T: Sync> Send for SubList<'_, T> {}
+
+unsafe impl<T: Sync> Sync for SubList<'_, T> {}
+
+unsafe impl<T: Send> Send for SubListMut<'_, T> {}
+
+unsafe impl<T: Sync> Sync for SubListMut<'_, T> {}
+
+impl<T> Clone for SubList<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SubList<'_, T> {}
+
+impl<'a, T> SubList<'a, T> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for SubList<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> SubListMut<'_, T> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for SubListMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> LinkedList<T> {
+    // The first index in the range and the one past its end, unchecked.
+    pub(crate) fn range_ends<R>(&self, range: &R) -> (usize, usize)
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len,
+        };
+
+        (start, end)
+    }
+
+    // Panics like slice indexing if the range is decreasing or runs past the
+    // end.
+    fn window<R>(&self, range: R) -> Iter<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = self.range_ends(&range);
+        assert!(
+            start <= end,
+            "Range starts at `{}` but ends at `{}`",
+            start,
+            end
+        );
+        assert!(
+            end <= self.len,
+            "Index out of bound: len is `{}` but range ends at `{}`",
+            self.len,
+            end
+        );
+
+        if start == end {
+            return Iter::default();
+        }
+
+        traversal_span!(
+            "range",
+            start.min(self.len - start) + end.min(self.len - end)
+        );
+
+        Iter {
+            head: Some(self.node_at(start)),
+            tail: Some(self.node_at(end - 1)),
+            len: end - start,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn range<R>(&self, range: R) -> SubList<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let Iter {
+            head, tail, len, ..
+        } = self.window(range);
+        SubList {
+            head,
+            tail,
+            len,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn range_mut<R>(&mut self, range: R) -> SubListMut<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let Iter {
+            head, tail, len, ..
+        } = self.window(range);
+        SubListMut {
+            head,
+            tail,
+            len,
+            marker: PhantomData,
+        }
+    }
+
+    // The halves never share a node, so both can be written at once.
+    pub fn split_at_mut(&mut self, at: usize) -> (SubListMut<'_, T>, SubListMut<'_, T>) {
+        assert!(
+            at <= self.len,
+            "Index out of bound: len is `{}` but index is `{}`",
+            self.len,
+            at
+        );
+
+        let (front_tail, back_head) = match at {
+            0 => (None, self.head),
+            _ if at == self.len => (self.tail, None),
+            _ => {
+                traversal_span!("split_at_mut", at.min(self.len - 1 - at));
+
+                let node = self.node_at(at);
+                (unsafe { node.as_ref() }.prev, Some(node))
+            }
+        };
+
+        let front = SubListMut {
+            head: front_tail.and(self.head),
+            tail: front_tail,
+            len: at,
+            marker: PhantomData,
+        };
+        let back = SubListMut {
+            head: back_head,
+            tail: back_head.and(self.tail),
+            len: self.len - at,
+            marker: PhantomData,
+        };
+
+        (front, back)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LinkedList;
+
+    #[test]
+    fn range_matches_slice_ranges() {
+        let list = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        let slice = [0, 1, 2, 3, 4, 5];
+
+        for start in 0..=6 {
+            for end in start..=6 {
+                let range = list.range(start..end);
+                assert_eq!(range.len(), end - start);
+                assert!(range.iter().eq(&slice[start..end]));
+                assert!(range.iter().rev().eq(slice[start..end].iter().rev()));
+            }
+        }
+
+        assert!(list.range(..).iter().eq(&slice));
+        assert!(list.range(2..=5).iter().eq(&slice[2..=5]));
+        assert!(list.range(6..).is_empty());
+    }
+
+    #[test]
+    fn range_mut_writes_only_its_window() {
+        let mut list = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        list.range_mut(1..3).iter_mut().for_each(|key| *key *= 10);
+        for key in list.range_mut(5..) {
+            *key = -1;
+        }
+        assert!(list.range_mut(0..0).iter_mut().next().is_none());
+
+        assert!(list.eq_by([0, 10, 20, 3, 4, -1], |a, b| *a == b));
+        list.debug_validate();
+    }
+
+    #[test]
+    #[should_panic(expected = "range ends at `7`")]
+    fn range_past_the_end_panics() {
+        LinkedList::from([0, 1, 2, 3, 4, 5]).range(2..7);
+    }
+
+    #[test]
+    #[should_panic(expected = "Range starts at `4`")]
+    fn decreasing_range_panics() {
+        let (start, end) = (4, 3);
+        LinkedList::from([0, 1, 2, 3, 4, 5]).range(start..end);
+    }
+
+    #[test]
+    fn split_at_mut_halves_are_disjoint_at_every_index() {
+        for at in 0..=6 {
+            let mut list = LinkedList::from([0, 1, 2, 3, 4, 5]);
+            let (mut front, mut back) = list.split_at_mut(at);
+            assert_eq!((front.len(), back.len()), (at, 6 - at));
+
+            let mut front = front.iter_mut();
+            let mut back = back.iter_mut().rev();
+            loop {
+                match (front.next(), back.next()) {
+                    (None, None) => break,
+                    (front, back) => {
+                        front.into_iter().for_each(|key| *key += 100);
+                        back.into_iter().for_each(|key| *key -= 100);
+                    }
+                }
+            }
+
+            assert!(list.eq_by(0..6, |key, at_key| {
+                *key == if at_key < at as i32 {
+                    at_key + 100
+                } else {
+                    at_key - 100
+                }
+            }));
+            list.debug_validate();
+        }
+    }
+
+    #[test]
+    fn split_at_mut_of_an_empty_list() {
+        let mut list = LinkedList::<i32>::new();
+        let (front, back) = list.split_at_mut(0);
+        assert!(front.is_empty() && back.is_empty());
+        assert!(front.iter().next().is_none() && back.into_iter().next().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "index is `7`")]
+    fn split_at_mut_past_the_end_panics() {
+        LinkedList::from([0, 1, 2, 3, 4, 5]).split_at_mut(7);
+    }
+}
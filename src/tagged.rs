@@ -0,0 +1,112 @@
+use core::{iter::FusedIterator, mem};
+
+use crate::{self as list, LinkedList};
+
+// Each node carries a tag next to its element. Elements and tags are stored
+// side by side in the same node, so tagging costs no extra allocation.
+pub struct TaggedList<T, M> {
+    list: LinkedList<(T, M)>,
+}
+
+pub struct Iter<'a, T, M> {
+    inner: list::Iter<'a, (T, M)>,
+}
+
+impl<'a, T, M> Iterator for Iter<'a, T, M> {
+    type Item = (&'a T, &'a M);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, tag)| (key, tag))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T, M> DoubleEndedIterator for Iter<'_, T, M> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(key, tag)| (key, tag))
+    }
+}
+
+impl<T, M> ExactSizeIterator for Iter<'_, T, M> {}
+
+impl<T, M> FusedIterator for Iter<'_, T, M> {}
+
+impl<T, M> TaggedList<T, M> {
+    pub const fn new() -> TaggedList<T, M> {
+        TaggedList {
+            list: LinkedList::new(),
+        }
+    }
+
+    pub fn into_inner(self) -> LinkedList<(T, M)> {
+        self.list
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T, M> {
+        Iter {
+            inner: self.list.iter(),
+        }
+    }
+
+    pub fn push_front(&mut self, key: T, tag: M) {
+        self.list.push_front((key, tag))
+    }
+
+    pub fn push_back(&mut self, key: T, tag: M) {
+        self.list.push_back((key, tag))
+    }
+
+    pub fn push_at(&mut self, at: usize, key: T, tag: M) {
+        self.list.push_at(at, (key, tag))
+    }
+
+    pub fn pop_front(&mut self) -> Option<(T, M)> {
+        self.list.pop_front()
+    }
+
+    pub fn pop_back(&mut self) -> Option<(T, M)> {
+        self.list.pop_back()
+    }
+
+    pub fn pop_at(&mut self, at: usize) -> Option<(T, M)> {
+        self.list.pop_at(at)
+    }
+
+    pub fn tag(&self, at: usize) -> Option<&M> {
+        self.list.iter().nth(at).map(|(_, tag)| tag)
+    }
+
+    pub fn tag_mut(&mut self, at: usize) -> Option<&mut M> {
+        self.list.iter_mut().nth(at).map(|(_, tag)| tag)
+    }
+
+    // Returns the previous tag, or hands `tag` back if `at` is out of bounds.
+    pub fn set_tag(&mut self, at: usize, tag: M) -> Result<M, M> {
+        match self.tag_mut(at) {
+            Some(slot) => Ok(mem::replace(slot, tag)),
+            None => Err(tag),
+        }
+    }
+}
+
+impl<T, M> Default for TaggedList<T, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, M> From<LinkedList<(T, M)>> for TaggedList<T, M> {
+    fn from(list: LinkedList<(T, M)>) -> Self {
+        TaggedList { list }
+    }
+}
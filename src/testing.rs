@@ -0,0 +1,117 @@
+// Helpers shared by the unit tests.
+
+// A seeded xorshift generator, so randomized tests replay the same way
+// every run.
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Rng {
+        assert!(seed != 0, "Xorshift cannot start from a zero seed");
+        Rng(seed)
+    }
+
+    pub(crate) fn below(&mut self, bound: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % bound
+    }
+
+    pub(crate) fn index(&mut self, bound: usize) -> usize {
+        self.below(bound as u64) as usize
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) use reclaim::*;
+
+#[cfg(feature = "std")]
+mod reclaim {
+    use std::{
+        any,
+        marker::PhantomData,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use crate::concurrent::reclaim;
+
+    // How many allocations of each type have been retired and how many of
+    // those freed, keyed by type name so tests running at the same time keep
+    // apart as long as their types differ.
+    static COUNTS: Mutex<Vec<(&'static str, usize, usize)>> = Mutex::new(Vec::new());
+
+    fn count<T>(update: impl FnOnce(&mut (&'static str, usize, usize))) {
+        let name = any::type_name::<T>();
+        let mut counts = COUNTS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let at = match counts.iter().position(|&(key, ..)| key == name) {
+            Some(at) => at,
+            None => {
+                counts.push((name, 0, 0));
+                counts.len() - 1
+            }
+        };
+        update(&mut counts[at]);
+    }
+
+    pub(crate) fn count_retired<T>() {
+        count::<T>(|(_, retired, _)| *retired += 1);
+    }
+
+    pub(crate) fn count_freed<T>() {
+        count::<T>(|(_, _, freed)| *freed += 1);
+    }
+
+    // Flushes the reclaimers until every `T` retired so far has been freed,
+    // and returns how many were. Panics if one is freed twice, or if they
+    // are not all freed within the time limit.
+    pub(crate) fn await_reclaimed<T>() -> usize {
+        let deadline = Instant::now() + Duration::from_secs(30);
+        loop {
+            reclaim::flush();
+
+            let mut counts = (0, 0);
+            count::<T>(|&mut (_, retired, freed)| counts = (retired, freed));
+            let (retired, freed) = counts;
+            assert!(freed <= retired, "{freed} freed of {retired} retired");
+            if freed == retired {
+                return freed;
+            }
+
+            assert!(
+                Instant::now() < deadline,
+                "only {freed} freed of {retired} retired"
+            );
+            thread::yield_now();
+        }
+    }
+
+    // An element that counts its drops. `R` is the reclaimer under test: it
+    // makes the nodes of each backend's test a type of their own for
+    // `await_reclaimed`.
+    pub(crate) struct Tracked<R> {
+        drops: Arc<AtomicUsize>,
+        marker: PhantomData<R>,
+    }
+
+    impl<R> Tracked<R> {
+        pub(crate) fn new(drops: &Arc<AtomicUsize>) -> Tracked<R> {
+            Tracked {
+                drops: drops.clone(),
+                marker: PhantomData,
+            }
+        }
+    }
+
+    impl<R> Drop for Tracked<R> {
+        fn drop(&mut self) {
+            self.drops.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
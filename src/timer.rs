@@ -0,0 +1,318 @@
+use std::{collections::HashMap, ptr::NonNull};
+
+use crate::{IntoIter, LinkedList, Node};
+
+const BITS: u32 = 6;
+
+const SLOTS: usize = 1 << BITS;
+
+const LEVELS: usize = 6;
+
+// Holds timers that were already due when inserted; drained first by the
+// next `advance`.
+const DUE: usize = LEVELS * SLOTS;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimerHandle(u64);
+
+struct Entry<T> {
+    id: u64,
+    deadline: u64,
+    item: T,
+}
+
+// The slot list a live timer is in, and its node there.
+type Location<T> = (usize, NonNull<Node<Entry<T>>>);
+
+// Hierarchical timing wheel: level `l` has 64 slots of 64^l ticks each, and
+// a timer sits on the lowest level whose span still separates its deadline
+// from the current tick. When the wheel reaches a slot on a higher level,
+// that slot's timers cascade down to finer ones. Timers move between slot
+// lists node by node, so the node address recorded for each live timer stays
+// valid until the timer fires or is cancelled, and cancelling just unlinks
+// it.
+pub struct TimerWheel<T> {
+    lists: Vec<LinkedList<Entry<T>>>,
+    timers: HashMap<u64, Location<T>>,
+    now: u64,
+    next_id: u64,
+}
+
+unsafe impl<T: Send> Send for TimerWheel<T> {}
+
+unsafe impl<T: Sync> Sync for TimerWheel<T> {}
+
+impl<T> TimerWheel<T> {
+    pub fn new(now: u64) -> TimerWheel<T> {
+        TimerWheel {
+            lists: (0..=DUE).map(|_| LinkedList::new()).collect(),
+            timers: HashMap::new(),
+            now,
+            next_id: 0,
+        }
+    }
+
+    pub fn now(&self) -> u64 {
+        self.now
+    }
+
+    pub fn len(&self) -> usize {
+        self.timers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+
+    // Deadlines further out than the top level reaches go on the top level
+    // all the same, in the slot their bits alias to; when that slot comes
+    // round early they are placed again.
+    fn list_for(&self, deadline: u64) -> usize {
+        if deadline <= self.now {
+            return DUE;
+        }
+
+        let differing = (deadline ^ self.now) | (SLOTS as u64 - 1);
+        let level = ((63 - differing.leading_zeros()) / BITS) as usize;
+        let level = level.min(LEVELS - 1);
+
+        let slot = (deadline >> (BITS * level as u32)) as usize % SLOTS;
+        level * SLOTS + slot
+    }
+
+    pub fn insert(&mut self, deadline: u64, item: T) -> TimerHandle {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let at = self.list_for(deadline);
+        let list = &mut self.lists[at];
+        list.push_back(Entry { id, deadline, item });
+        let node = unsafe { list.tail.unwrap_unchecked() };
+        self.timers.insert(id, (at, node));
+
+        TimerHandle(id)
+    }
+
+    // `None` if the timer has already fired or been cancelled.
+    pub fn cancel(&mut self, handle: TimerHandle) -> Option<T> {
+        let (at, node) = self.timers.remove(&handle.0)?;
+        let entry = unsafe { self.lists[at].unlink(node) };
+        Some(entry.item)
+    }
+
+    // Moves the wheel to `now` and hands back every timer whose deadline has
+    // passed: first those that were already due when inserted, then the rest
+    // in deadline order. Stretches with nothing to do are skipped.
+    pub fn advance(&mut self, now: u64) -> IntoIter<T> {
+        let mut expired = LinkedList::new();
+        self.expire(DUE, &mut expired);
+
+        while self.now < now {
+            match self.next_tick() {
+                Some(tick) if tick <= now => self.now = tick,
+                _ => {
+                    self.now = now;
+                    break;
+                }
+            }
+
+            for level in (1..LEVELS).rev() {
+                let span = BITS * level as u32;
+                if self.now & ((1 << span) - 1) == 0 {
+                    let slot = (self.now >> span) as usize % SLOTS;
+                    self.cascade(level * SLOTS + slot, &mut expired);
+                }
+            }
+
+            self.expire(self.now as usize % SLOTS, &mut expired);
+        }
+
+        expired.into_iter()
+    }
+
+    // The first tick after `now` at which the wheel reaches a non-empty slot.
+    fn next_tick(&self) -> Option<u64> {
+        let mut next = None;
+        for level in 0..LEVELS {
+            let span = BITS * level as u32;
+            let rotation = 1u64 << (span + BITS);
+
+            for slot in 0..SLOTS {
+                if self.lists[level * SLOTS + slot].is_empty() {
+                    continue;
+                }
+
+                let mut tick = self.now - self.now % rotation + ((slot as u64) << span);
+                if tick <= self.now {
+                    tick = tick.saturating_add(rotation);
+                }
+                next = Some(next.map_or(tick, |next: u64| next.min(tick)));
+            }
+        }
+
+        next
+    }
+
+    fn expire(&mut self, at: usize, expired: &mut LinkedList<T>) {
+        while let Some(entry) = self.lists[at].pop_front() {
+            self.timers.remove(&entry.id);
+            expired.push_back(entry.item);
+        }
+    }
+
+    // Takes the whole slot first, since a far-off timer can land right back
+    // in it.
+    fn cascade(&mut self, at: usize, expired: &mut LinkedList<T>) {
+        let mut list = self.lists[at].take();
+        while let Some(node) = list.pop_front_node() {
+            let (id, deadline) = {
+                let entry = &unsafe { node.as_ref() }.key;
+                (entry.id, entry.deadline)
+            };
+
+            if deadline <= self.now {
+                self.timers.remove(&id);
+                expired.push_back(unsafe { Node::free(node) }.item);
+                continue;
+            }
+
+            let to = self.list_for(deadline);
+            unsafe { self.lists[to].push_back_node(node) };
+            self.timers.insert(id, (to, node));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Rng;
+
+    fn fired(wheel: &mut TimerWheel<u64>, now: u64) -> Vec<u64> {
+        wheel.advance(now).collect()
+    }
+
+    #[test]
+    fn timers_fire_once_their_deadline_is_reached() {
+        let mut wheel = TimerWheel::new(10);
+        for deadline in [12, 11, 75, 11] {
+            wheel.insert(deadline, deadline);
+        }
+
+        assert!(fired(&mut wheel, 10).is_empty());
+        assert_eq!(fired(&mut wheel, 11), [11, 11]);
+        assert!(fired(&mut wheel, 11).is_empty());
+        assert_eq!(fired(&mut wheel, 74), [12]);
+        assert_eq!(fired(&mut wheel, 75), [75]);
+        assert!(wheel.is_empty());
+        assert_eq!(wheel.now(), 75);
+    }
+
+    #[test]
+    fn already_due_timers_fire_first() {
+        let mut wheel = TimerWheel::new(100);
+        wheel.insert(101, 101);
+        wheel.insert(50, 50);
+        wheel.insert(100, 100);
+
+        assert_eq!(fired(&mut wheel, 101), [50, 100, 101]);
+    }
+
+    #[test]
+    fn higher_levels_cascade_down() {
+        let mut wheel = TimerWheel::new(0);
+        let deadlines = [64 * 3 + 5, 64 * 64 + 1, 64 * 64 * 64 * 2 + 64 * 7 + 3];
+        for deadline in deadlines {
+            wheel.insert(deadline, deadline);
+        }
+
+        for deadline in deadlines {
+            assert!(fired(&mut wheel, deadline - 1).is_empty());
+            assert_eq!(fired(&mut wheel, deadline), [deadline]);
+        }
+    }
+
+    #[test]
+    fn one_long_advance_fires_in_deadline_order() {
+        let mut wheel = TimerWheel::new(0);
+        let deadlines = [70_000, 3, 64, 1 << 38, 4095, 4096, 65];
+        for deadline in deadlines {
+            wheel.insert(deadline, deadline);
+        }
+
+        let mut sorted = deadlines;
+        sorted.sort_unstable();
+        assert_eq!(fired(&mut wheel, 1 << 39), sorted);
+    }
+
+    #[test]
+    fn deadlines_past_the_top_level_still_fire_on_time() {
+        let top = 1 << (BITS * LEVELS as u32);
+        let mut wheel = TimerWheel::new(5);
+        let far = [top + 7, 3 * top + 1, top * 64 + 9];
+        for deadline in far {
+            wheel.insert(deadline, deadline);
+        }
+
+        assert!(fired(&mut wheel, top).is_empty());
+        for deadline in far {
+            assert!(fired(&mut wheel, deadline - 1).is_empty());
+            assert_eq!(fired(&mut wheel, deadline), [deadline]);
+        }
+        assert!(wheel.is_empty());
+    }
+
+    #[test]
+    fn cancel_unlinks_only_live_timers() {
+        let mut wheel = TimerWheel::new(0);
+        let near = wheel.insert(3, 3);
+        let far = wheel.insert(5000, 5000);
+        let fired_handle = wheel.insert(1, 1);
+
+        assert_eq!(fired(&mut wheel, 2), [1]);
+        assert_eq!(wheel.cancel(fired_handle), None);
+        assert_eq!(wheel.cancel(far), Some(5000));
+        assert_eq!(wheel.cancel(far), None);
+        assert_eq!(wheel.len(), 1);
+
+        assert!(fired(&mut wheel, 6000).eq(&[3]));
+        assert_eq!(wheel.cancel(near), None);
+    }
+
+    // Checks every batch against a plain scan of the pending deadlines.
+    #[test]
+    fn random_schedule_matches_a_naive_model() {
+        let mut rng = Rng::new(0x2545_f491);
+
+        let mut wheel = TimerWheel::new(0);
+        let mut pending: Vec<(u64, u64, TimerHandle)> = Vec::new();
+        for round in 0..400 {
+            let spans = [4, 300, 20_000, 2_000_000, 1 << 40];
+            for _ in 0..rng.below(4) {
+                let span = spans[rng.index(5)];
+                let deadline = wheel.now() + rng.below(span);
+                let handle = wheel.insert(deadline, round);
+                pending.push((deadline, round, handle));
+            }
+            if rng.below(8) == 0 && !pending.is_empty() {
+                let (_, item, handle) = pending.swap_remove(rng.index(pending.len()));
+                assert_eq!(wheel.cancel(handle), Some(item));
+            }
+
+            let span = spans[rng.index(5)];
+            let now = wheel.now() + rng.below(span);
+            let mut due: Vec<u64> = pending
+                .iter()
+                .filter(|(deadline, ..)| *deadline <= now)
+                .map(|(_, item, _)| *item)
+                .collect();
+            pending.retain(|(deadline, ..)| *deadline > now);
+
+            let mut got = fired(&mut wheel, now);
+            got.sort_unstable();
+            due.sort_unstable();
+            assert_eq!(got, due);
+            assert_eq!(wheel.len(), pending.len());
+        }
+    }
+}
@@ -0,0 +1,17 @@
+// Lists shorter than this are walked too quickly to be worth a span.
+#[cfg(feature = "tracing")]
+pub(crate) const TRACE_THRESHOLD: usize = 1024;
+
+// Enters a debug span for the rest of the enclosing block when an operation
+// is about to walk `$len` nodes and `$len` reaches `TRACE_THRESHOLD`.
+// Compiles to nothing without the `tracing` feature.
+macro_rules! traversal_span {
+    ($name:literal, $len:expr) => {
+        #[cfg(feature = "tracing")]
+        let _span = {
+            let len: usize = $len;
+            (len >= crate::trace::TRACE_THRESHOLD)
+                .then(|| tracing::debug_span!($name, len).entered())
+        };
+    };
+}
@@ -0,0 +1,237 @@
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use crate::LinkedList;
+
+enum Op<T> {
+    PushFront,
+    PushBack,
+    PushAt(usize),
+    PopFront(T),
+    PopBack(T),
+    PopAt(usize, T),
+    // The number of elements spliced in at the index.
+    Splice(usize, usize),
+}
+
+// Mutations made while a checkpoint is open are recorded so that `rollback`
+// can apply their inverses. A popped element moves into the log, so pops
+// hand out a reference to it rather than the element itself; outside a
+// checkpoint the log only keeps the latest operation, freeing the one
+// before.
+pub struct UndoList<T> {
+    list: LinkedList<T>,
+    log: Vec<Op<T>>,
+    checkpoints: Vec<usize>,
+}
+
+impl<T> UndoList<T> {
+    pub const fn new() -> UndoList<T> {
+        UndoList {
+            list: LinkedList::new(),
+            log: Vec::new(),
+            checkpoints: Vec::new(),
+        }
+    }
+
+    pub fn into_inner(self) -> LinkedList<T> {
+        self.list
+    }
+
+    pub fn checkpoint(&mut self) {
+        self.checkpoints.push(self.log.len());
+    }
+
+    pub fn commit(&mut self) -> bool {
+        if self.checkpoints.pop().is_none() {
+            return false;
+        }
+
+        if self.checkpoints.is_empty() {
+            self.log.clear();
+        }
+
+        true
+    }
+
+    pub fn rollback(&mut self) -> bool {
+        let Some(checkpoint) = self.checkpoints.pop() else {
+            return false;
+        };
+
+        while self.log.len() > checkpoint {
+            match self.log.pop() {
+                Some(Op::PushFront) => drop(self.list.pop_front()),
+                Some(Op::PushBack) => drop(self.list.pop_back()),
+                Some(Op::PushAt(at)) => drop(self.list.pop_at(at)),
+                Some(Op::PopFront(key)) => self.list.push_front(key),
+                Some(Op::PopBack(key)) => self.list.push_back(key),
+                Some(Op::PopAt(at, key)) => self.list.push_at(at, key),
+                Some(Op::Splice(at, len)) => {
+                    let mut spliced = self.list.split_off(at);
+                    let mut back = spliced.split_off(len);
+                    self.list.append(&mut back);
+                }
+                None => unreachable!(),
+            }
+        }
+
+        true
+    }
+
+    fn record(&mut self, op: Op<T>) {
+        if self.checkpoints.is_empty() {
+            self.log.clear();
+        }
+
+        self.log.push(op);
+    }
+
+    fn record_pop(&mut self, op: Op<T>) -> &T {
+        self.record(op);
+        match self.log.last() {
+            Some(Op::PopFront(key) | Op::PopBack(key) | Op::PopAt(_, key)) => key,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn push_front(&mut self, key: T) {
+        self.list.push_front(key);
+        self.record(Op::PushFront);
+    }
+
+    pub fn push_back(&mut self, key: T) {
+        self.list.push_back(key);
+        self.record(Op::PushBack);
+    }
+
+    pub fn push_at(&mut self, at: usize, key: T) {
+        self.list.push_at(at, key);
+        self.record(Op::PushAt(at));
+    }
+
+    pub fn pop_front(&mut self) -> Option<&T> {
+        let key = self.list.pop_front()?;
+        Some(self.record_pop(Op::PopFront(key)))
+    }
+
+    pub fn pop_back(&mut self) -> Option<&T> {
+        let key = self.list.pop_back()?;
+        Some(self.record_pop(Op::PopBack(key)))
+    }
+
+    pub fn pop_at(&mut self, at: usize) -> Option<&T> {
+        let key = self.list.pop_at(at)?;
+        Some(self.record_pop(Op::PopAt(at, key)))
+    }
+
+    // Moves every element of `other` in ahead of index `at`, leaving `other`
+    // empty. Rolling it back drops the moved elements, like rolling back a
+    // push.
+    pub fn splice_at(&mut self, at: usize, other: &mut LinkedList<T>) {
+        let len = other.len();
+        let mut back = self.list.split_off(at);
+        self.list.append(other);
+        self.list.append(&mut back);
+        self.record(Op::Splice(at, len));
+    }
+
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        let at = self.list.len();
+        self.splice_at(at, other);
+    }
+}
+
+impl<T> Deref for UndoList<T> {
+    type Target = LinkedList<T>;
+    fn deref(&self) -> &LinkedList<T> {
+        &self.list
+    }
+}
+
+impl<T> Default for UndoList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<LinkedList<T>> for UndoList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        UndoList {
+            list,
+            log: Vec::new(),
+            checkpoints: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Not `Clone`, so pops have to keep the element itself.
+    #[derive(Debug, PartialEq)]
+    struct Key(i32);
+
+    fn keys(list: &UndoList<Key>) -> Vec<i32> {
+        list.iter().map(|key| key.0).collect()
+    }
+
+    #[test]
+    fn rollback_reverses_each_operation() {
+        let mut list: UndoList<Key> = (0..4).map(Key).collect::<LinkedList<_>>().into();
+
+        list.checkpoint();
+        list.push_front(Key(-1));
+        list.push_back(Key(4));
+        list.push_at(2, Key(9));
+        assert_eq!(list.pop_front(), Some(&Key(-1)));
+        assert_eq!(list.pop_back(), Some(&Key(4)));
+        assert_eq!(list.pop_at(1), Some(&Key(9)));
+        assert_eq!(list.pop_at(0), Some(&Key(0)));
+        list.splice_at(1, &mut LinkedList::from([Key(7), Key(8)]));
+        list.append(&mut LinkedList::from([Key(5)]));
+        assert_eq!(keys(&list), [1, 7, 8, 2, 3, 5]);
+
+        assert!(list.rollback());
+        list.debug_validate();
+        assert_eq!(keys(&list), [0, 1, 2, 3]);
+        assert!(!list.rollback());
+    }
+
+    #[test]
+    fn nested_checkpoints_roll_back_separately() {
+        let mut list = UndoList::new();
+        list.push_back(Key(0));
+
+        list.checkpoint();
+        list.push_back(Key(1));
+        list.checkpoint();
+        list.pop_front();
+        list.push_back(Key(2));
+        assert!(list.rollback());
+        assert_eq!(keys(&list), [0, 1]);
+
+        list.checkpoint();
+        list.pop_back();
+        assert!(list.commit());
+        assert_eq!(keys(&list), [0]);
+        assert!(list.rollback());
+        assert_eq!(keys(&list), [0]);
+        assert!(!list.commit());
+    }
+
+    #[test]
+    fn committed_changes_stay() {
+        let mut list = UndoList::new();
+        list.checkpoint();
+        list.push_back(Key(1));
+        list.splice_at(0, &mut LinkedList::from([Key(0)]));
+        assert!(list.commit());
+        assert!(!list.rollback());
+
+        assert_eq!(list.pop_back(), Some(&Key(1)));
+        let list = list.into_inner();
+        assert!(list.iter().eq(&[Key(0)]));
+    }
+}
@@ -0,0 +1,244 @@
+use core::{error::Error, fmt, iter::FusedIterator, ptr::NonNull};
+
+use crate::{
+    atomic::{AtomicUsize, Ordering},
+    Iter, LinkedList, Node,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VersionMismatch {
+    pub expected: u64,
+    pub found: u64,
+}
+
+impl fmt::Display for VersionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "list is at version `{}` but version `{}` was expected",
+            self.found, self.expected
+        )
+    }
+}
+
+impl Error for VersionMismatch {}
+
+// A saved iteration position. It borrows nothing; the list it came from
+// checks it on `resume`, and only accepts it if the list has the same id and
+// version it had when the token was taken, so the saved nodes still exist.
+pub struct IterToken<T> {
+    id: usize,
+    version: u64,
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+}
+
+unsafe impl<T> Send for IterToken<T> {}
+
+unsafe impl<T> Sync for IterToken<T> {}
+
+impl<T> Clone for IterToken<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for IterToken<T> {}
+
+pub struct Resumable<'a, T> {
+    inner: Iter<'a, T>,
+    id: usize,
+    version: u64,
+}
+
+impl<'a, T> Iterator for Resumable<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Resumable<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Resumable<'_, T> {}
+
+impl<T> FusedIterator for Resumable<'_, T> {}
+
+impl<T> Resumable<'_, T> {
+    pub fn checkpoint(&self) -> IterToken<T> {
+        IterToken {
+            id: self.id,
+            version: self.version,
+            head: self.inner.head,
+            tail: self.inner.tail,
+            len: self.inner.len,
+        }
+    }
+}
+
+// The version goes up on every change to the list's structure: pushes,
+// pops, appends, splits, takes and swaps. Writing through `iter_mut` or
+// `get_many_mut` changes elements, not structure, and leaves it alone.
+impl<T> LinkedList<T> {
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub fn iter_if_unchanged(&self, version: u64) -> Result<Iter<'_, T>, VersionMismatch> {
+        if self.version != version {
+            return Err(VersionMismatch {
+                expected: version,
+                found: self.version,
+            });
+        }
+
+        Ok(self.iter())
+    }
+
+    // Versions only count changes within one list, so tokens also carry an
+    // id that tells lists apart. It is handed out when first needed.
+    pub(crate) fn id(&self) -> usize {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+
+        let id = self.id.load(Ordering::Relaxed);
+        if id != 0 {
+            return id;
+        }
+
+        let fresh = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        match self
+            .id
+            .compare_exchange(0, fresh, Ordering::Relaxed, Ordering::Relaxed)
+        {
+            Ok(_) => fresh,
+            Err(id) => id,
+        }
+    }
+
+    pub fn iter_resumable(&self) -> Resumable<'_, T> {
+        Resumable {
+            inner: self.iter(),
+            id: self.id(),
+            version: self.version,
+        }
+    }
+
+    // A token taken from another list is rejected like a stale one.
+    pub fn resume(&self, token: IterToken<T>) -> Result<Resumable<'_, T>, VersionMismatch> {
+        if token.id != self.id() || token.version != self.version {
+            return Err(VersionMismatch {
+                expected: token.version,
+                found: self.version,
+            });
+        }
+
+        let mut inner = self.iter();
+        inner.head = token.head;
+        inner.tail = token.tail;
+        inner.len = token.len;
+
+        Ok(Resumable {
+            inner,
+            id: token.id,
+            version: token.version,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LinkedList;
+
+    #[test]
+    fn resume_picks_up_where_the_checkpoint_was() {
+        let list = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        let mut iter = list.iter_resumable();
+        iter.next();
+        iter.next_back();
+        let token = iter.checkpoint();
+
+        let resumed = list.resume(token).unwrap();
+        assert_eq!(resumed.len(), 4);
+        assert!(resumed.eq([1, 2, 3, 4].iter()));
+    }
+
+    #[test]
+    fn structural_changes_make_tokens_stale() {
+        let mut list = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        let token = list.iter_resumable().checkpoint();
+        let version = list.version();
+
+        list.iter_mut().for_each(|key| *key += 1);
+        assert!(list.resume(token).is_ok());
+        assert!(list.iter_if_unchanged(version).is_ok());
+
+        list.push_back(6);
+        let err = list.resume(token).err().unwrap();
+        assert_eq!((err.expected, err.found), (version, list.version()));
+        assert!(list.iter_if_unchanged(version).is_err());
+    }
+
+    #[test]
+    fn take_moves_token_validity_with_the_nodes() {
+        let mut list = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        let token = list.iter_resumable().checkpoint();
+
+        let taken = list.take();
+        assert!(list.resume(token).is_err());
+        assert!(taken.resume(token).unwrap().eq(taken.iter()));
+
+        list.push_back(0);
+        assert!(list.resume(token).is_err());
+    }
+
+    #[test]
+    fn swap_with_makes_both_lists_reject_old_tokens() {
+        let mut first = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        let mut second = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        let first_token = first.iter_resumable().checkpoint();
+        let second_token = second.iter_resumable().checkpoint();
+
+        first.swap_with(&mut second);
+        for list in [&first, &second] {
+            assert!(list.resume(first_token).is_err());
+            assert!(list.resume(second_token).is_err());
+        }
+    }
+
+    #[test]
+    fn split_off_makes_both_halves_reject_old_tokens() {
+        let mut list = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        let token = list.iter_resumable().checkpoint();
+
+        let back = list.split_off(3);
+        assert!(list.resume(token).is_err());
+        assert!(back.resume(token).is_err());
+
+        let token = list.iter_resumable().checkpoint();
+        assert!(list.split_off(list.len()).is_empty());
+        assert!(list.resume(token).is_ok());
+
+        let front = list.split_off(0);
+        assert!(list.resume(token).is_err());
+        assert!(front.resume(token).is_ok());
+    }
+
+    #[test]
+    fn tokens_from_another_list_are_rejected() {
+        let first = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        let second = LinkedList::from([0, 1, 2, 3, 4, 5]);
+        assert_eq!(first.version(), second.version());
+
+        let token = first.iter_resumable().checkpoint();
+        assert!(second.resume(token).is_err());
+    }
+}
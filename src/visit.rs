@@ -0,0 +1,172 @@
+use core::ptr::NonNull;
+
+use crate::{LinkedList, Node};
+
+// What the closure given to `for_each_ctx` sees of the element it is
+// visiting. Elements it inserts land right next to that element, in call
+// order: `insert_before` ones ahead of it and `insert_after` ones behind it.
+// Once the element is removed, later insertions go where it was.
+pub struct VisitContext<'a, T> {
+    list: &'a mut LinkedList<T>,
+    node: Option<NonNull<Node<T>>>,
+    // Insertion points: `before` is the node in front of the slot new
+    // elements go to ahead of the current one, `after` the last node behind
+    // it. `None` means the front of the list.
+    before: Option<NonNull<Node<T>>>,
+    after: Option<NonNull<Node<T>>>,
+}
+
+impl<T> VisitContext<'_, T> {
+    fn node(&self) -> NonNull<Node<T>> {
+        self.node
+            .expect("The current element has already been removed")
+    }
+
+    pub fn is_removed(&self) -> bool {
+        self.node.is_none()
+    }
+
+    // Panics if the current element has been removed, like the two below.
+    pub fn current(&self) -> &T {
+        &unsafe { &*self.node().as_ptr() }.key
+    }
+
+    pub fn current_mut(&mut self) -> &mut T {
+        &mut unsafe { &mut *self.node().as_ptr() }.key
+    }
+
+    pub fn remove_current(&mut self) -> T {
+        let node = self.node();
+        self.node = None;
+        if self.after == Some(node) {
+            self.after = self.before;
+        }
+
+        unsafe { self.list.unlink(node) }
+    }
+
+    fn insert(&mut self, at: Option<NonNull<Node<T>>>, key: T) -> NonNull<Node<T>> {
+        match at {
+            Some(node) => unsafe { self.list.push_after(node, key) },
+            None => {
+                self.list.push_front(key);
+                unsafe { self.list.head.unwrap_unchecked() }
+            }
+        }
+    }
+
+    pub fn insert_before(&mut self, key: T) {
+        let added = self.insert(self.before, key);
+        if self.node.is_none() && self.after == self.before {
+            self.after = Some(added);
+        }
+        self.before = Some(added);
+    }
+
+    pub fn insert_after(&mut self, key: T) {
+        let added = self.insert(self.after, key);
+        self.after = Some(added);
+    }
+}
+
+impl<T> LinkedList<T> {
+    // Visits every element once, front to back. Elements inserted through
+    // the context are not visited themselves.
+    pub fn for_each_ctx<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut VisitContext<'_, T>),
+    {
+        traversal_span!("for_each_ctx", self.len);
+
+        let mut node = self.head;
+        while let Some(curr) = node {
+            let (prev, next) = {
+                let curr = unsafe { curr.as_ref() };
+                (curr.prev, curr.next)
+            };
+
+            f(&mut VisitContext {
+                list: self,
+                node: Some(curr),
+                before: prev,
+                after: Some(curr),
+            });
+            node = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn visit<F>(f: F) -> Vec<i32>
+    where
+        F: FnMut(&mut VisitContext<'_, i32>),
+    {
+        let mut list: LinkedList<i32> = (1..=5).collect();
+        list.for_each_ctx(f);
+        list.debug_validate();
+        list.into_iter().collect()
+    }
+
+    #[test]
+    fn removes_and_edits_in_place() {
+        let evens = visit(|ctx| {
+            if *ctx.current() % 2 == 0 {
+                assert_eq!(ctx.remove_current() % 2, 0);
+                assert!(ctx.is_removed());
+            }
+        });
+        assert_eq!(evens, [1, 3, 5]);
+
+        let doubled = visit(|ctx| *ctx.current_mut() *= 2);
+        assert_eq!(doubled, [2, 4, 6, 8, 10]);
+    }
+
+    #[test]
+    fn inserts_keep_call_order_and_are_not_visited() {
+        let mut seen = Vec::new();
+        let list = visit(|ctx| {
+            let key = *ctx.current();
+            seen.push(key);
+            ctx.insert_before(key * 10);
+            ctx.insert_after(-key);
+            ctx.insert_after(-key - 100);
+        });
+
+        assert_eq!(seen, [1, 2, 3, 4, 5]);
+        assert_eq!(&list[..4], [10, 1, -1, -101]);
+        assert_eq!(&list[16..], [50, 5, -5, -105]);
+    }
+
+    #[test]
+    fn inserts_after_a_removal_fill_the_gap() {
+        let list = visit(|ctx| {
+            let key = *ctx.current();
+            if key == 1 || key == 5 {
+                ctx.insert_after(100 + key);
+                ctx.remove_current();
+                ctx.insert_before(key * 10);
+                ctx.insert_after(200 + key);
+            }
+        });
+        assert_eq!(list, [10, 101, 201, 2, 3, 4, 50, 105, 205]);
+
+        let list = visit(|ctx| {
+            let key = ctx.remove_current();
+            ctx.insert_after(key);
+            ctx.insert_before(-key);
+        });
+        assert_eq!(list, [-1, 1, -2, 2, -3, 3, -4, 4, -5, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "already been removed")]
+    fn current_after_removal_panics() {
+        visit(|ctx| {
+            ctx.remove_current();
+            ctx.current();
+        });
+    }
+}
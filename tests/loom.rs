@@ -0,0 +1,303 @@
+// Run with `RUSTFLAGS="--cfg loom" cargo test --release --test loom`.
+//
+// The list reclaimers keep their bookkeeping in std thread-locals, which loom
+// does not model, so these use `Leak` and check the push/pop protocols alone.
+#![cfg(loom)]
+
+use linked_list::{
+    concurrent::{
+        reclaim::Leak, LockFreeQueue, LockFreeStack, MpscNode, MpscQueue, SpscQueue,
+        WorkStealingDeque,
+    },
+    RcuList,
+};
+use loom::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+};
+
+#[test]
+fn stack_concurrent_push() {
+    loom::model(|| {
+        let stack = Arc::new(LockFreeStack::with_reclaimer(Leak));
+
+        let handles: Vec<_> = (0..2)
+            .map(|key| {
+                let stack = stack.clone();
+                thread::spawn(move || stack.push(key))
+            })
+            .collect();
+        handles
+            .into_iter()
+            .for_each(|handle| handle.join().unwrap());
+
+        let mut popped = [stack.pop().unwrap(), stack.pop().unwrap()];
+        popped.sort();
+        assert_eq!(popped, [0, 1]);
+        assert_eq!(stack.pop(), None);
+    });
+}
+
+#[test]
+fn stack_push_pop_race() {
+    loom::model(|| {
+        let stack = Arc::new(LockFreeStack::with_reclaimer(Leak));
+        stack.push(0);
+
+        let pusher = {
+            let stack = stack.clone();
+            thread::spawn(move || stack.push(1))
+        };
+        let popper = {
+            let stack = stack.clone();
+            thread::spawn(move || stack.pop())
+        };
+
+        pusher.join().unwrap();
+        let first = popper.join().unwrap().unwrap();
+        let second = stack.pop().unwrap();
+        assert_eq!(first + second, 1);
+        assert_eq!(stack.pop(), None);
+    });
+}
+
+#[test]
+fn stack_concurrent_pop() {
+    loom::model(|| {
+        let stack = Arc::new(LockFreeStack::with_reclaimer(Leak));
+        stack.push(0);
+        stack.push(1);
+
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let stack = stack.clone();
+                thread::spawn(move || stack.pop())
+            })
+            .collect();
+        let mut popped: Vec<_> = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap().unwrap())
+            .collect();
+        popped.sort();
+        assert_eq!(popped, [0, 1]);
+    });
+}
+
+#[test]
+fn stack_iter_pop_race() {
+    loom::model(|| {
+        let stack = Arc::new(LockFreeStack::with_reclaimer(Leak));
+        stack.push(0);
+        stack.push(1);
+
+        let popper = {
+            let stack = stack.clone();
+            thread::spawn(move || stack.pop())
+        };
+
+        // Whatever the walk sees is a run of the stack from some point down,
+        // and it may stop early at a node that was popped under it.
+        let seen: Vec<_> = stack.iter().collect();
+        assert!([&[1, 0][..], &[1], &[0]].contains(&&seen[..]));
+
+        assert_eq!(popper.join().unwrap(), Some(1));
+        assert_eq!(stack.iter().collect::<Vec<_>>(), [0]);
+    });
+}
+
+#[test]
+fn queue_concurrent_push() {
+    loom::model(|| {
+        let queue = Arc::new(LockFreeQueue::with_reclaimer(Leak));
+
+        let handles: Vec<_> = (0..2)
+            .map(|key| {
+                let queue = queue.clone();
+                thread::spawn(move || queue.push(key))
+            })
+            .collect();
+        handles
+            .into_iter()
+            .for_each(|handle| handle.join().unwrap());
+
+        let mut popped = [queue.pop().unwrap(), queue.pop().unwrap()];
+        popped.sort();
+        assert_eq!(popped, [0, 1]);
+        assert_eq!(queue.pop(), None);
+    });
+}
+
+#[test]
+fn queue_push_pop_race() {
+    loom::model(|| {
+        let queue = Arc::new(LockFreeQueue::with_reclaimer(Leak));
+
+        let pusher = {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                queue.push(0);
+                queue.push(1);
+            })
+        };
+        let popper = {
+            let queue = queue.clone();
+            thread::spawn(move || queue.pop())
+        };
+
+        pusher.join().unwrap();
+        match popper.join().unwrap() {
+            Some(first) => {
+                assert_eq!(first, 0);
+                assert_eq!(queue.pop(), Some(1));
+            }
+            None => {
+                assert_eq!(queue.pop(), Some(0));
+                assert_eq!(queue.pop(), Some(1));
+            }
+        }
+        assert_eq!(queue.pop(), None);
+    });
+}
+
+#[test]
+fn deque_pop_steal_race() {
+    loom::model(|| {
+        let deque = WorkStealingDeque::with_reclaimer(Leak);
+        deque.push(0);
+        deque.push(1);
+
+        let thief = {
+            let stealer = deque.stealer();
+            thread::spawn(move || stealer.steal())
+        };
+
+        let mut taken: Vec<_> = [deque.pop(), deque.pop()].into_iter().flatten().collect();
+        taken.extend(thief.join().unwrap());
+        taken.sort();
+        assert_eq!(taken, [0, 1]);
+        assert!(deque.is_empty());
+    });
+}
+
+#[test]
+fn spsc_push_pop_race() {
+    loom::model(|| {
+        let (mut producer, mut consumer) = SpscQueue::with_capacity(1).split();
+
+        let pusher = thread::spawn(move || {
+            producer.push(0).unwrap();
+            while producer.push(1).is_err() {
+                thread::yield_now();
+            }
+        });
+
+        let mut popped = Vec::new();
+        while popped.len() < 2 {
+            match consumer.pop() {
+                Some(key) => popped.push(key),
+                None => thread::yield_now(),
+            }
+        }
+
+        pusher.join().unwrap();
+        assert_eq!(popped, [0, 1]);
+        assert_eq!(consumer.pop(), None);
+    });
+}
+
+#[test]
+fn mpsc_concurrent_push() {
+    loom::model(|| {
+        let (producer, mut consumer) = MpscQueue::new().split();
+
+        let pushers: Vec<_> = (0..2)
+            .map(|key| {
+                let producer = producer.clone();
+                thread::spawn(move || producer.push(MpscNode::new(key)))
+            })
+            .collect();
+        pushers
+            .into_iter()
+            .for_each(|pusher| pusher.join().unwrap());
+
+        let mut popped = [0, 1].map(|_| consumer.pop().unwrap().into_inner());
+        popped.sort();
+        assert_eq!(popped, [0, 1]);
+        assert!(consumer.pop().is_none());
+    });
+}
+
+#[test]
+fn mpsc_push_pop_race() {
+    loom::model(|| {
+        let (producer, mut consumer) = MpscQueue::new().split();
+
+        let pusher = thread::spawn(move || {
+            producer.push(MpscNode::new(0));
+            producer.push(MpscNode::new(1));
+        });
+
+        // A pop that runs into a half-linked push comes back empty; the
+        // rest is there once the pusher is done.
+        let mut popped: Vec<_> = [consumer.pop(), consumer.pop()]
+            .into_iter()
+            .flatten()
+            .map(|node| node.into_inner())
+            .collect();
+        pusher.join().unwrap();
+        while let Some(node) = consumer.pop() {
+            popped.push(node.into_inner());
+        }
+        assert_eq!(popped, [0, 1]);
+    });
+}
+
+// Marks its slot in `dropped` when it goes, so a reader can tell whether a
+// node it saw has been freed under it.
+struct Probe {
+    key: usize,
+    dropped: Arc<[AtomicBool; 2]>,
+}
+
+impl Drop for Probe {
+    fn drop(&mut self) {
+        self.dropped[self.key].store(true, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn rcu_pop_waits_for_reader() {
+    loom::model(|| {
+        let dropped = Arc::new([AtomicBool::new(false), AtomicBool::new(false)]);
+        let list = Arc::new(RcuList::new());
+        for key in 0..2 {
+            list.push_back(Probe {
+                key,
+                dropped: dropped.clone(),
+            });
+        }
+
+        // Whichever side of the writer's epoch flips the reader lands on, it
+        // sees the list before or after the pop, and nothing it sees is
+        // freed while it holds the guard.
+        let reader = {
+            let (list, dropped) = (list.clone(), dropped.clone());
+            thread::spawn(move || {
+                let guard = list.read();
+                let keys: Vec<_> = guard.iter().map(|probe| probe.key).collect();
+                assert!(keys == [0, 1] || keys == [1]);
+                for key in keys {
+                    assert!(!dropped[key].load(Ordering::SeqCst));
+                }
+            })
+        };
+
+        assert_eq!(list.pop_front().map(|probe| probe.key), Some(0));
+        assert!(dropped[0].load(Ordering::SeqCst));
+        reader.join().unwrap();
+        assert!(!dropped[1].load(Ordering::SeqCst));
+    });
+}